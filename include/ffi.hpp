@@ -1,14 +1,98 @@
 #include <iostream>
+#include <cstring>
+#include <opencv2/core.hpp>
 
 using namespace std;
 
+enum FFIErrorKind
+{
+    FFI_ERROR_OPENCV = 0,
+    FFI_ERROR_STD = 1,
+    FFI_ERROR_UNKNOWN = 2,
+};
+
+// Owned copy of a caught exception. Released by cv_release_error.
+struct FFIError
+{
+    int kind;
+    int code;
+    char *message;
+    char *function;
+    char *file;
+    int line;
+};
+
+template <typename T>
+struct Point_t
+{
+    T x;
+    T y;
+};
+
+template <typename T>
+struct Size_t
+{
+    T width;
+    T height;
+};
+
+template <typename T>
+struct Rect_t
+{
+    T x;
+    T y;
+    T width;
+    T height;
+};
+typedef Point_t<int> Point2i;
+typedef Point2i Point;
+typedef Point_t<float> Point2f;
+typedef Point_t<double> Point2d;
+typedef Size_t<int> Size2i;
+typedef Size2i Size;
+typedef Rect_t<int> Rect2i;
+typedef Rect2i Rect;
+
+struct Scalar
+{
+    double val[4];
+};
+
+inline cv::Scalar to_cv_scalar(Scalar s)
+{
+    return cv::Scalar(s.val[0], s.val[1], s.val[2], s.val[3]);
+}
+
+inline Scalar from_cv_scalar(const cv::Scalar &s)
+{
+    return {{s[0], s[1], s[2], s[3]}};
+}
+
+// Optional Mat arguments arrive as null pointers; an empty Mat means "not given" to OpenCV.
+inline cv::Mat or_empty(cv::Mat *pointer)
+{
+    return pointer ? *pointer : cv::Mat();
+}
+
 template <typename T>
 struct FFIResult
 {
     T ok;
-    char *error;
+    FFIError *error;
 };
 
+inline char *copy_string(const std::string &s)
+{
+    char *dst = new char[s.size() + 1];
+    std::memcpy(dst, s.c_str(), s.size() + 1);
+    return dst;
+}
+
+inline FFIError *new_error(int kind, int code, const std::string &message, const std::string &function, const std::string &file, int line)
+{
+    return new FFIError{kind, code, copy_string(message), copy_string(function), copy_string(file), line};
+}
+
 template <typename T, class F>
 FFIResult<T> try_execute(F f, T v)
 {
@@ -16,8 +100,16 @@ FFIResult<T> try_execute(F f, T v)
     {
         return {f(), nullptr};
     }
+    catch (cv::Exception &e)
+    {
+        return {v, new_error(FFI_ERROR_OPENCV, e.code, e.err, e.func, e.file, e.line)};
+    }
     catch (std::exception &e)
     {
-        return {v, (char *)(e.what())};
+        return {v, new_error(FFI_ERROR_STD, 0, e.what(), "", "", 0)};
+    }
+    catch (...)
+    {
+        return {v, new_error(FFI_ERROR_UNKNOWN, 0, "", "", "", 0)};
     }
 };
@@ -1,3 +1,6 @@
+#include <limits>
+#include <memory>
+#include <sstream>
 #include <vector>
 #include <opencv2/core.hpp>
 #include "ffi.hpp"
@@ -25,7 +28,37 @@ extern "C"
     MatResult cv_mat_from_shape_vec(int rows, int cols, int type, void *data)
     {
         return try_execute<cv::Mat *>([&]()
-                                      { return new cv::Mat(cv::Size(cols, rows), type, data); },
+                                      { return new cv::Mat(cv::Mat(cv::Size(cols, rows), type, data).clone()); },
+                                      nullptr);
+    }
+
+    MatResult cv_mat_nd_zeros(int dims, const int *sizes, int type)
+    {
+        return try_execute<cv::Mat *>([&]()
+                                      { return new cv::Mat(cv::Mat::zeros(dims, sizes, type)); },
+                                      nullptr);
+    }
+
+    MatResult cv_mat_nd_from_shape_vec(int dims, const int *sizes, int type, void *data)
+    {
+        return try_execute<cv::Mat *>([&]()
+                                      { return new cv::Mat(cv::Mat(dims, sizes, type, data).clone()); },
+                                      nullptr);
+    }
+
+    // New header over the same buffer; the Mat must be continuous.
+    MatResult cv_mat_reshape_nd(cv::Mat *pointer, int cn, int dims, const int *sizes)
+    {
+        return try_execute<cv::Mat *>([&]()
+                                      { return new cv::Mat(pointer->reshape(cn, dims, sizes)); },
+                                      nullptr);
+    }
+
+    // Header over caller-owned memory; nothing is copied or freed.
+    MatResult cv_mat_from_data(int rows, int cols, int type, void *data, size_t step)
+    {
+        return try_execute<cv::Mat *>([&]()
+                                      { return new cv::Mat(rows, cols, type, data, step); },
                                       nullptr);
     }
 
@@ -36,6 +69,67 @@ extern "C"
                                       nullptr);
     }
 
+    MatResult cv_mat_zeros(int rows, int cols, int type)
+    {
+        return try_execute<cv::Mat *>([&]()
+                                      { return new cv::Mat(cv::Mat::zeros(cv::Size(cols, rows), type)); },
+                                      nullptr);
+    }
+
+    MatResult cv_mat_eye(int rows, int cols, int type)
+    {
+        return try_execute<cv::Mat *>([&]()
+                                      { return new cv::Mat(cv::Mat::eye(cv::Size(cols, rows), type)); },
+                                      nullptr);
+    }
+
+    MatResult cv_mat_from_scalar(int rows, int cols, int type, Scalar s)
+    {
+        return try_execute<cv::Mat *>([&]()
+                                      { return new cv::Mat(cv::Size(cols, rows), type, to_cv_scalar(s)); },
+                                      nullptr);
+    }
+
+    MatResult cv_mat_clone(cv::Mat *pointer)
+    {
+        return try_execute<cv::Mat *>([&]()
+                                      { return new cv::Mat(pointer->clone()); },
+                                      nullptr);
+    }
+
+    MatResult cv_mat_share(cv::Mat *pointer)
+    {
+        return try_execute<cv::Mat *>([&]()
+                                      { return new cv::Mat(*pointer); },
+                                      nullptr);
+    }
+
+    MatResult cv_mat_roi(cv::Mat *pointer, Rect rect)
+    {
+        return try_execute<cv::Mat *>([&]()
+                                      { return new cv::Mat(*pointer, cv::Rect(rect.x, rect.y, rect.width, rect.height)); },
+                                      nullptr);
+    }
+
+    MatResult cv_mat_row_range(cv::Mat *pointer, int start, int end)
+    {
+        return try_execute<cv::Mat *>([&]()
+                                      { return new cv::Mat(pointer->rowRange(start, end)); },
+                                      nullptr);
+    }
+
+    MatResult cv_mat_col_range(cv::Mat *pointer, int start, int end)
+    {
+        return try_execute<cv::Mat *>([&]()
+                                      { return new cv::Mat(pointer->colRange(start, end)); },
+                                      nullptr);
+    }
+
+    bool cv_mat_is_continuous(cv::Mat *pointer)
+    {
+        return pointer->isContinuous();
+    }
+
     int cv_mat_type(cv::Mat *pointer)
     {
         return pointer->type();
@@ -46,9 +140,30 @@ extern "C"
         return pointer->data;
     }
 
+    size_t cv_mat_step(cv::Mat *pointer)
+    {
+        return pointer->step[0];
+    }
+
+    // Element count including channels; rows and cols are -1 for N-d Mats.
     int cv_mat_size(cv::Mat *pointer)
     {
-        return pointer->cols * pointer->rows * pointer->channels();
+        return static_cast<int>(pointer->total() * pointer->channels());
+    }
+
+    int cv_mat_dims(cv::Mat *pointer)
+    {
+        return pointer->dims;
+    }
+
+    // `sizes` and `steps` must hold `dims` values.
+    void cv_mat_shape(cv::Mat *pointer, int *sizes, size_t *steps)
+    {
+        for (int i = 0; i < pointer->dims; i++)
+        {
+            sizes[i] = pointer->size[i];
+            steps[i] = pointer->step[i];
+        }
     }
 
     int cv_mat_cols(cv::Mat *pointer)
@@ -70,4 +185,839 @@ extern "C"
     {
         delete pointer;
     }
-}
\ No newline at end of file
+
+    void cv_release_error(FFIError *error)
+    {
+        delete[] error->message;
+        delete[] error->function;
+        delete[] error->file;
+        delete error;
+    }
+}
+
+// Arithmetic
+extern "C"
+{
+    FFIResult<int> cv_add(cv::Mat *src1, cv::Mat *src2, cv::Mat *dst, cv::Mat *mask, int dtype)
+    {
+        return try_execute<int>([&]()
+                                { cv::add(*src1, *src2, *dst, or_empty(mask), dtype); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_add_scalar(cv::Mat *src1, Scalar src2, cv::Mat *dst, cv::Mat *mask, int dtype)
+    {
+        return try_execute<int>([&]()
+                                { cv::add(*src1, to_cv_scalar(src2), *dst, or_empty(mask), dtype); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_subtract(cv::Mat *src1, cv::Mat *src2, cv::Mat *dst, cv::Mat *mask, int dtype)
+    {
+        return try_execute<int>([&]()
+                                { cv::subtract(*src1, *src2, *dst, or_empty(mask), dtype); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_subtract_scalar(cv::Mat *src1, Scalar src2, cv::Mat *dst, cv::Mat *mask, int dtype)
+    {
+        return try_execute<int>([&]()
+                                { cv::subtract(*src1, to_cv_scalar(src2), *dst, or_empty(mask), dtype); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_multiply(cv::Mat *src1, cv::Mat *src2, cv::Mat *dst, double scale, int dtype)
+    {
+        return try_execute<int>([&]()
+                                { cv::multiply(*src1, *src2, *dst, scale, dtype); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_multiply_scalar(cv::Mat *src1, Scalar src2, cv::Mat *dst, double scale, int dtype)
+    {
+        return try_execute<int>([&]()
+                                { cv::multiply(*src1, to_cv_scalar(src2), *dst, scale, dtype); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_divide(cv::Mat *src1, cv::Mat *src2, cv::Mat *dst, double scale, int dtype)
+    {
+        return try_execute<int>([&]()
+                                { cv::divide(*src1, *src2, *dst, scale, dtype); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_divide_scalar(cv::Mat *src1, Scalar src2, cv::Mat *dst, double scale, int dtype)
+    {
+        return try_execute<int>([&]()
+                                { cv::divide(*src1, to_cv_scalar(src2), *dst, scale, dtype); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_absdiff(cv::Mat *src1, cv::Mat *src2, cv::Mat *dst)
+    {
+        return try_execute<int>([&]()
+                                { cv::absdiff(*src1, *src2, *dst); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_add_weighted(cv::Mat *src1, double alpha, cv::Mat *src2, double beta, double gamma, cv::Mat *dst, int dtype)
+    {
+        return try_execute<int>([&]()
+                                { cv::addWeighted(*src1, alpha, *src2, beta, gamma, *dst, dtype); return 0; },
+                                -1);
+    }
+}
+
+// Conversion
+extern "C"
+{
+    FFIResult<int> cv_mat_convert_to(cv::Mat *src, cv::Mat *dst, int rtype, double alpha, double beta)
+    {
+        return try_execute<int>([&]()
+                                { src->convertTo(*dst, rtype, alpha, beta); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_convert_scale_abs(cv::Mat *src, cv::Mat *dst, double alpha, double beta)
+    {
+        return try_execute<int>([&]()
+                                { cv::convertScaleAbs(*src, *dst, alpha, beta); return 0; },
+                                -1);
+    }
+}
+
+// Channels
+extern "C"
+{
+    FFIResult<int> cv_split(cv::Mat *src, cv::Mat **dst, size_t count)
+    {
+        return try_execute<int>([&]()
+                                {
+                                    vector<cv::Mat> mv;
+                                    cv::split(*src, mv);
+                                    CV_Assert(mv.size() == count);
+                                    for (size_t i = 0; i < count; i++)
+                                        *dst[i] = mv[i];
+                                    return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_merge(cv::Mat **src, size_t count, cv::Mat *dst)
+    {
+        return try_execute<int>([&]()
+                                {
+                                    vector<cv::Mat> mv;
+                                    for (size_t i = 0; i < count; i++)
+                                        mv.push_back(*src[i]);
+                                    cv::merge(mv, *dst);
+                                    return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_extract_channel(cv::Mat *src, cv::Mat *dst, int coi)
+    {
+        return try_execute<int>([&]()
+                                { cv::extractChannel(*src, *dst, coi); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_insert_channel(cv::Mat *src, cv::Mat *dst, int coi)
+    {
+        return try_execute<int>([&]()
+                                { cv::insertChannel(*src, *dst, coi); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_mix_channels(cv::Mat *src, cv::Mat *dst, int dtype, int *from_to, size_t npairs)
+    {
+        return try_execute<int>([&]()
+                                {
+                                    dst->create(src->size(), dtype);
+                                    cv::mixChannels(src, 1, dst, 1, from_to, npairs);
+                                    return 0; },
+                                -1);
+    }
+}
+
+// Statistics
+extern "C"
+{
+    FFIResult<Scalar> cv_sum(cv::Mat *src)
+    {
+        return try_execute<Scalar>([&]()
+                                   { return from_cv_scalar(cv::sum(*src)); },
+                                   Scalar{});
+    }
+
+    FFIResult<Scalar> cv_mean(cv::Mat *src, cv::Mat *mask)
+    {
+        return try_execute<Scalar>([&]()
+                                   { return from_cv_scalar(cv::mean(*src, or_empty(mask))); },
+                                   Scalar{});
+    }
+
+    FFIResult<int> cv_mean_std_dev(cv::Mat *src, Scalar *mean, Scalar *stddev, cv::Mat *mask)
+    {
+        return try_execute<int>([&]()
+                                {
+                                    cv::Scalar m, s;
+                                    cv::meanStdDev(*src, m, s, or_empty(mask));
+                                    *mean = from_cv_scalar(m);
+                                    *stddev = from_cv_scalar(s);
+                                    return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_min_max_loc(cv::Mat *src, double *min_val, double *max_val, Point *min_loc, Point *max_loc, cv::Mat *mask)
+    {
+        return try_execute<int>([&]()
+                                {
+                                    cv::Point min_p, max_p;
+                                    cv::minMaxLoc(*src, min_val, max_val, &min_p, &max_p, or_empty(mask));
+                                    *min_loc = {min_p.x, min_p.y};
+                                    *max_loc = {max_p.x, max_p.y};
+                                    return 0; },
+                                -1);
+    }
+
+    // cv::countNonZero has no mask argument; masked-out elements are zeroed first.
+    FFIResult<int> cv_count_non_zero(cv::Mat *src, cv::Mat *mask)
+    {
+        return try_execute<int>([&]()
+                                {
+                                    if (!mask)
+                                        return cv::countNonZero(*src);
+                                    cv::Mat masked = cv::Mat::zeros(src->size(), src->type());
+                                    src->copyTo(masked, *mask);
+                                    return cv::countNonZero(masked); },
+                                -1);
+    }
+
+    FFIResult<double> cv_norm(cv::Mat *src, int norm_type, cv::Mat *mask)
+    {
+        return try_execute<double>([&]()
+                                   { return cv::norm(*src, norm_type, or_empty(mask)); },
+                                   -1.);
+    }
+
+    FFIResult<double> cv_norm_diff(cv::Mat *src1, cv::Mat *src2, int norm_type, cv::Mat *mask)
+    {
+        return try_execute<double>([&]()
+                                   { return cv::norm(*src1, *src2, norm_type, or_empty(mask)); },
+                                   -1.);
+    }
+}
+
+// Bitwise
+// With a mask, elements outside it are left at zero rather than uninitialized.
+static void zero_if_masked(cv::Mat *src, cv::Mat *dst, cv::Mat *mask)
+{
+    if (mask)
+        *dst = cv::Mat::zeros(src->size(), src->type());
+}
+
+extern "C"
+{
+    FFIResult<int> cv_bitwise_and(cv::Mat *src1, cv::Mat *src2, cv::Mat *dst, cv::Mat *mask)
+    {
+        return try_execute<int>([&]()
+                                {
+                                    zero_if_masked(src1, dst, mask);
+                                    cv::bitwise_and(*src1, *src2, *dst, or_empty(mask));
+                                    return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_bitwise_or(cv::Mat *src1, cv::Mat *src2, cv::Mat *dst, cv::Mat *mask)
+    {
+        return try_execute<int>([&]()
+                                {
+                                    zero_if_masked(src1, dst, mask);
+                                    cv::bitwise_or(*src1, *src2, *dst, or_empty(mask));
+                                    return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_bitwise_xor(cv::Mat *src1, cv::Mat *src2, cv::Mat *dst, cv::Mat *mask)
+    {
+        return try_execute<int>([&]()
+                                {
+                                    zero_if_masked(src1, dst, mask);
+                                    cv::bitwise_xor(*src1, *src2, *dst, or_empty(mask));
+                                    return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_bitwise_not(cv::Mat *src, cv::Mat *dst, cv::Mat *mask)
+    {
+        return try_execute<int>([&]()
+                                {
+                                    zero_if_masked(src, dst, mask);
+                                    cv::bitwise_not(*src, *dst, or_empty(mask));
+                                    return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_in_range(cv::Mat *src, Scalar lower, Scalar upper, cv::Mat *dst)
+    {
+        return try_execute<int>([&]()
+                                { cv::inRange(*src, to_cv_scalar(lower), to_cv_scalar(upper), *dst); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_mat_copy_to_masked(cv::Mat *src, cv::Mat *dst, cv::Mat *mask)
+    {
+        return try_execute<int>([&]()
+                                { src->copyTo(*dst, *mask); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_mat_set_to(cv::Mat *src, Scalar value, cv::Mat *mask)
+    {
+        return try_execute<int>([&]()
+                                { src->setTo(to_cv_scalar(value), or_empty(mask)); return 0; },
+                                -1);
+    }
+}
+
+// Layout
+extern "C"
+{
+    FFIResult<int> cv_transpose(cv::Mat *src, cv::Mat *dst)
+    {
+        return try_execute<int>([&]()
+                                { cv::transpose(*src, *dst); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_flip(cv::Mat *src, cv::Mat *dst, int flip_code)
+    {
+        return try_execute<int>([&]()
+                                { cv::flip(*src, *dst, flip_code); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_rotate(cv::Mat *src, cv::Mat *dst, int rotate_code)
+    {
+        return try_execute<int>([&]()
+                                { cv::rotate(*src, *dst, rotate_code); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_repeat(cv::Mat *src, int ny, int nx, cv::Mat *dst)
+    {
+        return try_execute<int>([&]()
+                                { cv::repeat(*src, ny, nx, *dst); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_hconcat(cv::Mat **src, size_t count, cv::Mat *dst)
+    {
+        return try_execute<int>([&]()
+                                {
+                                    vector<cv::Mat> mats;
+                                    for (size_t i = 0; i < count; i++)
+                                        mats.push_back(*src[i]);
+                                    cv::hconcat(mats, *dst);
+                                    return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_vconcat(cv::Mat **src, size_t count, cv::Mat *dst)
+    {
+        return try_execute<int>([&]()
+                                {
+                                    vector<cv::Mat> mats;
+                                    for (size_t i = 0; i < count; i++)
+                                        mats.push_back(*src[i]);
+                                    cv::vconcat(mats, *dst);
+                                    return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_copy_make_border(cv::Mat *src, cv::Mat *dst, int top, int bottom, int left, int right, int border_type, Scalar value)
+    {
+        return try_execute<int>([&]()
+                                { cv::copyMakeBorder(*src, *dst, top, bottom, left, right, border_type, to_cv_scalar(value)); return 0; },
+                                -1);
+    }
+}
+
+// Linear algebra
+extern "C"
+{
+    FFIResult<int> cv_gemm(cv::Mat *src1, cv::Mat *src2, double alpha, cv::Mat *src3, double beta, cv::Mat *dst, int flags)
+    {
+        return try_execute<int>([&]()
+                                { cv::gemm(*src1, *src2, alpha, or_empty(src3), beta, *dst, flags); return 0; },
+                                -1);
+    }
+
+    FFIResult<double> cv_invert(cv::Mat *src, cv::Mat *dst, int flags)
+    {
+        return try_execute<double>([&]()
+                                   { return cv::invert(*src, *dst, flags); },
+                                   0.);
+    }
+
+    FFIResult<int> cv_solve(cv::Mat *src1, cv::Mat *src2, cv::Mat *dst, int flags)
+    {
+        return try_execute<int>([&]()
+                                { return cv::solve(*src1, *src2, *dst, flags) ? 1 : 0; },
+                                -1);
+    }
+
+    FFIResult<double> cv_determinant(cv::Mat *src)
+    {
+        return try_execute<double>([&]()
+                                   { return cv::determinant(*src); },
+                                   0.);
+    }
+
+    FFIResult<double> cv_trace(cv::Mat *src)
+    {
+        return try_execute<double>([&]()
+                                   { return cv::trace(*src)[0]; },
+                                   0.);
+    }
+
+    FFIResult<int> cv_svd_compute(cv::Mat *src, cv::Mat *w, cv::Mat *u, cv::Mat *vt, int flags)
+    {
+        return try_execute<int>([&]()
+                                { cv::SVD::compute(*src, *w, *u, *vt, flags); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_eigen(cv::Mat *src, cv::Mat *values, cv::Mat *vectors)
+    {
+        return try_execute<int>([&]()
+                                { return cv::eigen(*src, *values, *vectors) ? 1 : 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_eigen_non_symmetric(cv::Mat *src, cv::Mat *values, cv::Mat *vectors)
+    {
+        return try_execute<int>([&]()
+                                { cv::eigenNonSymmetric(*src, *values, *vectors); return 0; },
+                                -1);
+    }
+
+    FFIResult<double> cv_solve_poly(cv::Mat *coeffs, cv::Mat *roots, int max_iters)
+    {
+        return try_execute<double>([&]()
+                                   { return cv::solvePoly(*coeffs, *roots, max_iters); },
+                                   0.);
+    }
+
+    FFIResult<int> cv_solve_cubic(cv::Mat *coeffs, cv::Mat *roots)
+    {
+        return try_execute<int>([&]()
+                                { return cv::solveCubic(*coeffs, *roots); },
+                                -1);
+    }
+}
+
+// Fourier transform
+extern "C"
+{
+    FFIResult<int> cv_dft(cv::Mat *src, cv::Mat *dst, int flags)
+    {
+        return try_execute<int>([&]()
+                                { cv::dft(*src, *dst, flags); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_dct(cv::Mat *src, cv::Mat *dst, int flags)
+    {
+        return try_execute<int>([&]()
+                                { cv::dct(*src, *dst, flags); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_mul_spectrums(cv::Mat *a, cv::Mat *b, cv::Mat *c, int flags, bool conj_b)
+    {
+        return try_execute<int>([&]()
+                                { cv::mulSpectrums(*a, *b, *c, flags, conj_b); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_get_optimal_dft_size(int vecsize)
+    {
+        return try_execute<int>([&]()
+                                { return cv::getOptimalDFTSize(vecsize); },
+                                -1);
+    }
+
+    // Roll rows and columns so that index 0 lands at the center (fftshift),
+    // or back again when `inverse` is set (ifftshift). Odd sizes round as numpy does.
+    FFIResult<int> cv_shift_quadrants(cv::Mat *src, cv::Mat *dst, bool inverse)
+    {
+        return try_execute<int>([&]()
+                                {
+                                    int kx = inverse ? src->cols / 2 : src->cols - src->cols / 2;
+                                    int ky = inverse ? src->rows / 2 : src->rows - src->rows / 2;
+                                    int w = src->cols - kx;
+                                    int h = src->rows - ky;
+                                    dst->create(src->size(), src->type());
+                                    (*src)(cv::Rect(kx, ky, w, h)).copyTo((*dst)(cv::Rect(0, 0, w, h)));
+                                    (*src)(cv::Rect(0, ky, kx, h)).copyTo((*dst)(cv::Rect(w, 0, kx, h)));
+                                    (*src)(cv::Rect(kx, 0, w, ky)).copyTo((*dst)(cv::Rect(0, h, w, ky)));
+                                    (*src)(cv::Rect(0, 0, kx, ky)).copyTo((*dst)(cv::Rect(w, h, kx, ky)));
+                                    return 0; },
+                                -1);
+    }
+
+    // log(1 + |z|) of a two-channel complex Mat.
+    FFIResult<int> cv_log_magnitude(cv::Mat *src, cv::Mat *dst)
+    {
+        return try_execute<int>([&]()
+                                {
+                                    CV_Assert(src->channels() == 2);
+                                    cv::Mat planes[2];
+                                    cv::split(*src, planes);
+                                    cv::magnitude(planes[0], planes[1], *dst);
+                                    *dst += cv::Scalar::all(1);
+                                    cv::log(*dst, *dst);
+                                    return 0; },
+                                -1);
+    }
+}
+
+// Random
+// Run `f` with the calling thread's default generator, or with one restored
+// from `state` whose new state is written back.
+template <class F>
+static void with_rng(uint64_t *state, F f)
+{
+    if (!state)
+    {
+        f(cv::theRNG());
+        return;
+    }
+    cv::RNG rng(*state);
+    f(rng);
+    *state = rng.state;
+}
+
+extern "C"
+{
+    FFIResult<int> cv_rng_fill(uint64_t *state, cv::Mat *mat, int dist_type, Scalar a, Scalar b)
+    {
+        return try_execute<int>([&]()
+                                { with_rng(state, [&](cv::RNG &rng)
+                                           { rng.fill(*mat, dist_type, to_cv_scalar(a), to_cv_scalar(b)); });
+                                  return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_rand_shuffle(uint64_t *state, cv::Mat *mat, double iter_factor)
+    {
+        return try_execute<int>([&]()
+                                { with_rng(state, [&](cv::RNG &rng)
+                                           { cv::randShuffle(*mat, iter_factor, &rng); });
+                                  return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_set_rng_seed(int seed)
+    {
+        return try_execute<int>([&]()
+                                { cv::setRNGSeed(seed); return 0; },
+                                -1);
+    }
+}
+
+// Element-wise math
+extern "C"
+{
+    FFIResult<int> cv_normalize(cv::Mat *src, cv::Mat *dst, double alpha, double beta, int norm_type, cv::Mat *mask)
+    {
+        return try_execute<int>([&]()
+                                { cv::normalize(*src, *dst, alpha, beta, norm_type, -1, or_empty(mask)); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_exp(cv::Mat *src, cv::Mat *dst)
+    {
+        return try_execute<int>([&]()
+                                { cv::exp(*src, *dst); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_log(cv::Mat *src, cv::Mat *dst)
+    {
+        return try_execute<int>([&]()
+                                { cv::log(*src, *dst); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_pow(cv::Mat *src, double power, cv::Mat *dst)
+    {
+        return try_execute<int>([&]()
+                                { cv::pow(*src, power, *dst); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_sqrt(cv::Mat *src, cv::Mat *dst)
+    {
+        return try_execute<int>([&]()
+                                { cv::sqrt(*src, *dst); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_magnitude(cv::Mat *x, cv::Mat *y, cv::Mat *magnitude)
+    {
+        return try_execute<int>([&]()
+                                { cv::magnitude(*x, *y, *magnitude); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_phase(cv::Mat *x, cv::Mat *y, cv::Mat *angle, bool angle_in_degrees)
+    {
+        return try_execute<int>([&]()
+                                { cv::phase(*x, *y, *angle, angle_in_degrees); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_cart_to_polar(cv::Mat *x, cv::Mat *y, cv::Mat *magnitude, cv::Mat *angle, bool angle_in_degrees)
+    {
+        return try_execute<int>([&]()
+                                { cv::cartToPolar(*x, *y, *magnitude, *angle, angle_in_degrees); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_polar_to_cart(cv::Mat *magnitude, cv::Mat *angle, cv::Mat *x, cv::Mat *y, bool angle_in_degrees)
+    {
+        return try_execute<int>([&]()
+                                { cv::polarToCart(*magnitude, *angle, *x, *y, angle_in_degrees); return 0; },
+                                -1);
+    }
+
+    // cv::patchNaNs only accepts CV_32F; NaN is the only value unequal to itself,
+    // which covers CV_64F and any channel count as well.
+    FFIResult<int> cv_patch_nans(cv::Mat *a, double val)
+    {
+        return try_execute<int>([&]()
+                                {
+                                    cv::Mat flat = a->reshape(1);
+                                    cv::Mat mask;
+                                    cv::compare(flat, flat, mask, cv::CMP_NE);
+                                    flat.setTo(val, mask);
+                                    return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_check_range(cv::Mat *src, Point *pos, double min_val, double max_val)
+    {
+        return try_execute<int>([&]()
+                                {
+                                    cv::Point pt(-1, -1);
+                                    bool ok = cv::checkRange(*src, true, &pt, min_val, max_val);
+                                    *pos = Point{pt.x, pt.y};
+                                    return ok ? 1 : 0; },
+                                -1);
+    }
+}
+
+// Persistence
+static void assign_string(std::vector<uchar> *dst, const std::string &s)
+{
+    dst->assign(s.begin(), s.end());
+}
+
+extern "C"
+{
+    FFIResult<cv::FileStorage *> cv_file_storage_open(char *source, int flags)
+    {
+        return try_execute<cv::FileStorage *>([&]()
+                                              {
+                                                  auto fs = std::make_unique<cv::FileStorage>(source, flags);
+                                                  if (!fs->isOpened())
+                                                      CV_Error(cv::Error::StsError, "could not open FileStorage");
+                                                  return fs.release(); },
+                                              nullptr);
+    }
+
+    void cv_release_file_storage(cv::FileStorage *fs)
+    {
+        delete fs;
+    }
+
+    FFIResult<int> cv_file_storage_release(cv::FileStorage *fs)
+    {
+        return try_execute<int>([&]()
+                                { fs->release(); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_file_storage_release_and_get_string(cv::FileStorage *fs, std::vector<uchar> *dst)
+    {
+        return try_execute<int>([&]()
+                                { assign_string(dst, fs->releaseAndGetString()); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_file_storage_write_int(cv::FileStorage *fs, char *name, int value)
+    {
+        return try_execute<int>([&]()
+                                { fs->write(name, value); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_file_storage_write_real(cv::FileStorage *fs, char *name, double value)
+    {
+        return try_execute<int>([&]()
+                                { fs->write(name, value); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_file_storage_write_string(cv::FileStorage *fs, char *name, char *value)
+    {
+        return try_execute<int>([&]()
+                                { fs->write(name, cv::String(value)); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_file_storage_write_mat(cv::FileStorage *fs, char *name, cv::Mat *value)
+    {
+        return try_execute<int>([&]()
+                                { fs->write(name, *value); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_file_storage_start_write_struct(cv::FileStorage *fs, char *name, int flags, char *type_name)
+    {
+        return try_execute<int>([&]()
+                                { fs->startWriteStruct(name, flags, type_name); return 0; },
+                                -1);
+    }
+
+    FFIResult<int> cv_file_storage_end_write_struct(cv::FileStorage *fs)
+    {
+        return try_execute<int>([&]()
+                                { fs->endWriteStruct(); return 0; },
+                                -1);
+    }
+
+    FFIResult<cv::FileNode *> cv_file_storage_root(cv::FileStorage *fs)
+    {
+        return try_execute<cv::FileNode *>([&]()
+                                           { return new cv::FileNode(fs->root()); },
+                                           nullptr);
+    }
+
+    void cv_release_file_node(cv::FileNode *node)
+    {
+        delete node;
+    }
+
+    int cv_file_node_type(cv::FileNode *node)
+    {
+        return node->type();
+    }
+
+    size_t cv_file_node_size(cv::FileNode *node)
+    {
+        return node->size();
+    }
+
+    FFIResult<int> cv_file_node_name(cv::FileNode *node, std::vector<uchar> *dst)
+    {
+        return try_execute<int>([&]()
+                                { assign_string(dst, node->name()); return 0; },
+                                -1);
+    }
+
+    FFIResult<cv::FileNode *> cv_file_node_get(cv::FileNode *node, char *name)
+    {
+        return try_execute<cv::FileNode *>([&]()
+                                           { return new cv::FileNode((*node)[name]); },
+                                           nullptr);
+    }
+
+    // Walks the children in order, so it also reaches the entries of a map.
+    FFIResult<cv::FileNode *> cv_file_node_at(cv::FileNode *node, size_t index)
+    {
+        return try_execute<cv::FileNode *>([&]()
+                                           {
+                                               CV_Assert(index < node->size());
+                                               cv::FileNodeIterator it = node->begin();
+                                               it += (int)index;
+                                               return new cv::FileNode(*it); },
+                                           nullptr);
+    }
+
+    FFIResult<int> cv_file_node_int(cv::FileNode *node)
+    {
+        return try_execute<int>([&]()
+                                { return (int)*node; },
+                                0);
+    }
+
+    FFIResult<double> cv_file_node_real(cv::FileNode *node)
+    {
+        return try_execute<double>([&]()
+                                   { return node->real(); },
+                                   0.);
+    }
+
+    FFIResult<int> cv_file_node_string(cv::FileNode *node, std::vector<uchar> *dst)
+    {
+        return try_execute<int>([&]()
+                                { assign_string(dst, node->string()); return 0; },
+                                -1);
+    }
+
+    // Returns the type of the Mat read, which the caller checks.
+    FFIResult<int> cv_file_node_mat(cv::FileNode *node, cv::Mat *dst)
+    {
+        return try_execute<int>([&]()
+                                {
+                                    cv::read(*node, *dst);
+                                    return dst->type(); },
+                                -1);
+    }
+}
+
+// Formatting and comparison
+extern "C"
+{
+    FFIResult<int> cv_mat_format(cv::Mat *src, int fmt, std::vector<uchar> *dst)
+    {
+        return try_execute<int>([&]()
+                                {
+                                    std::ostringstream out;
+                                    out << cv::format(*src, static_cast<cv::Formatter::FormatType>(fmt));
+                                    assign_string(dst, out.str());
+                                    return 0; },
+                                -1);
+    }
+
+    // Largest |a - b| over all elements, compared in CV_64F so that no type
+    // saturates, and its pixel location. Equal elements differ by 0, which
+    // absdiff gets wrong for equal infinities; NaN elements count as infinite.
+    FFIResult<double> cv_mat_max_abs_diff(cv::Mat *a, cv::Mat *b, Point *loc)
+    {
+        return try_execute<double>([&]()
+                                   {
+                                       CV_Assert(a->size == b->size && a->type() == b->type());
+                                       *loc = {0, 0};
+                                       if (a->empty())
+                                           return 0.;
+                                       cv::Mat x, y, diff, same, nan;
+                                       a->reshape(1).convertTo(x, CV_64F);
+                                       b->reshape(1).convertTo(y, CV_64F);
+                                       cv::absdiff(x, y, diff);
+                                       cv::compare(x, y, same, cv::CMP_EQ);
+                                       diff.setTo(0, same);
+                                       cv::compare(diff, diff, nan, cv::CMP_NE);
+                                       diff.setTo(std::numeric_limits<double>::infinity(), nan);
+                                       double max_val = 0;
+                                       cv::Point max_loc;
+                                       cv::minMaxLoc(diff, nullptr, &max_val, nullptr, &max_loc);
+                                       *loc = {max_loc.x / a->channels(), max_loc.y};
+                                       return max_val; },
+                                   -1.);
+    }
+}
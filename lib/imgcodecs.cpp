@@ -1,24 +1,80 @@
 #include <vector>
 #include <opencv2/core.hpp>
 #include <opencv2/imgcodecs.hpp>
+#include <opencv2/imgproc.hpp>
 #include "ffi.hpp"
 
 using MatResult = FFIResult<cv::Mat *>;
 using namespace std;
 
+// IMREAD_UNCHANGED keeps the file's depth and channel count; bring it to 8UC4.
+static cv::Mat to_bgra(const cv::Mat &img)
+{
+    cv::Mat dst = img;
+    switch (dst.depth())
+    {
+    case CV_8U:
+        break;
+    case CV_16U:
+        dst.convertTo(dst, CV_8U, 1. / 256.);
+        break;
+    // Float images hold values in [0, 1].
+    case CV_16F:
+    case CV_32F:
+    case CV_64F:
+        dst.convertTo(dst, CV_8U, 255.);
+        break;
+    default:
+        dst.convertTo(dst, CV_8U);
+    }
+    if (dst.channels() == 1)
+        cv::cvtColor(dst, dst, cv::COLOR_GRAY2BGRA);
+    else if (dst.channels() == 2)
+    {
+        // Gray and alpha.
+        cv::Mat bgra(dst.size(), CV_8UC4);
+        const int from_to[] = {0, 0, 0, 1, 0, 2, 1, 3};
+        cv::mixChannels(&dst, 1, &bgra, 1, from_to, 4);
+        dst = bgra;
+    }
+    else if (dst.channels() == 3)
+        cv::cvtColor(dst, dst, cv::COLOR_BGR2BGRA);
+    if (dst.type() != CV_8UC4)
+        CV_Error_(cv::Error::StsUnsupportedFormat, ("cannot convert a %d-channel image to BGRA", img.channels()));
+    return dst;
+}
+
 extern "C"
 {
     FFIResult<int> cv_imwrite(cv::Mat *img, const char *path)
     {
         return try_execute<int>([&]()
-                                { cv::imwrite(path, *img); return 0; },
+                                {
+                                    if (!cv::imwrite(path, *img))
+                                        CV_Error(cv::Error::StsError, "could not write image");
+                                    return 0; },
                                 -1);
     }
 
     MatResult cv_imread(const char *path, int flags)
     {
         return try_execute<cv::Mat *>([&]()
-                                      { return new cv::Mat(cv::imread(path, flags)); },
+                                      {
+                                          cv::Mat img = cv::imread(path, flags);
+                                          if (img.empty())
+                                              CV_Error(cv::Error::StsError, "could not read image");
+                                          return new cv::Mat(img); },
+                                      nullptr);
+    }
+
+    MatResult cv_imread_bgra(const char *path)
+    {
+        return try_execute<cv::Mat *>([&]()
+                                      {
+                                          cv::Mat img = cv::imread(path, cv::IMREAD_UNCHANGED);
+                                          if (img.empty())
+                                              CV_Error(cv::Error::StsError, "could not read image");
+                                          return new cv::Mat(to_bgra(img)); },
                                       nullptr);
     }
 
@@ -29,11 +85,38 @@ extern "C"
                                 -1);
     }
 
+    FFIResult<int> cv_imencode_with_params(cv::Mat *img, std::vector<uchar> *dst, char *ext, int *params, size_t size)
+    {
+        vector<int> flags(params, params + size);
+        return try_execute<int>([&]()
+                                {
+                                    if (!cv::imencode(ext, *img, *dst, flags))
+                                        CV_Error(cv::Error::StsError, "could not encode image");
+                                    return 0; },
+                                -1);
+    }
+
     MatResult cv_imdecode(uchar *data, int size, int flags)
     {
         vector<uchar> bytes(data, data + size);
         return try_execute<cv::Mat *>([&]()
-                                      { return new cv::Mat(cv::imdecode(bytes, flags)); },
+                                      {
+                                          cv::Mat img = cv::imdecode(bytes, flags);
+                                          if (img.empty())
+                                              CV_Error(cv::Error::StsError, "could not decode image");
+                                          return new cv::Mat(img); },
+                                      nullptr);
+    }
+
+    MatResult cv_imdecode_bgra(uchar *data, int size)
+    {
+        vector<uchar> bytes(data, data + size);
+        return try_execute<cv::Mat *>([&]()
+                                      {
+                                          cv::Mat img = cv::imdecode(bytes, cv::IMREAD_UNCHANGED);
+                                          if (img.empty())
+                                              CV_Error(cv::Error::StsError, "could not decode image");
+                                          return new cv::Mat(to_bgra(img)); },
                                       nullptr);
     }
 
@@ -9,25 +9,6 @@ using namespace std;
 typedef std::vector<std::vector<cv::Point>> Contours;
 typedef std::vector<cv::Point> Contour;
 
-template <typename T>
-struct Point_t
-{
-    T x;
-    T y;
-};
-
-template <typename T>
-struct Size_t
-{
-    T width;
-    T height;
-};
-typedef Point_t<int> Point2i;
-typedef Point2i Point;
-typedef Point_t<float> Point2f;
-typedef Size_t<int> Size2i;
-typedef Size2i Size;
-
 // ImageFiltering
 extern "C"
 {
@@ -201,6 +182,26 @@ extern "C"
     }
 }
 
+// Motion Analysis and Object Tracking
+extern "C"
+{
+    FFIResult<Point2d> cv_phase_correlate(cv::Mat *src1, cv::Mat *src2, cv::Mat *window, double *response)
+    {
+        return try_execute<Point2d>([&]()
+                                    {
+                                        cv::Point2d shift = cv::phaseCorrelate(*src1, *src2, or_empty(window), response);
+                                        return Point2d{shift.x, shift.y}; },
+                                    Point2d{});
+    }
+
+    FFIResult<int> cv_create_hanning_window(cv::Mat *dst, Size win_size, int type)
+    {
+        return try_execute<int>([&]()
+                                { cv::createHanningWindow(*dst, cv::Size(win_size.width, win_size.height), type); return 0; },
+                                -1);
+    }
+}
+
 extern "C"
 {
     FFIResult<int> cv_cvt_color(cv::Mat *src, cv::Mat *dst, int code)
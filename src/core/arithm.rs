@@ -0,0 +1,425 @@
+//! Per-element arithmetic with OpenCV saturation semantics.
+//!
+//! The operators panic where OpenCV fails, such as on Mats of different sizes.
+//! The `*_masked` and `*_scaled` methods return the error instead.
+use super::{Depth, Mat, MatPointer, Scalar};
+use crate::result::Result;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+mod ffi {
+    use crate::{
+        core::{MatPointer, Scalar},
+        ffi::FFIResult,
+    };
+
+    #[link(name = "rxcv", kind = "static")]
+    extern "C" {
+        pub(super) fn cv_add(
+            src1: *const MatPointer,
+            src2: *const MatPointer,
+            dst: *const MatPointer,
+            mask: *const MatPointer,
+            dtype: i32,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_add_scalar(
+            src1: *const MatPointer,
+            src2: Scalar,
+            dst: *const MatPointer,
+            mask: *const MatPointer,
+            dtype: i32,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_subtract(
+            src1: *const MatPointer,
+            src2: *const MatPointer,
+            dst: *const MatPointer,
+            mask: *const MatPointer,
+            dtype: i32,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_subtract_scalar(
+            src1: *const MatPointer,
+            src2: Scalar,
+            dst: *const MatPointer,
+            mask: *const MatPointer,
+            dtype: i32,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_multiply(
+            src1: *const MatPointer,
+            src2: *const MatPointer,
+            dst: *const MatPointer,
+            scale: f64,
+            dtype: i32,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_multiply_scalar(
+            src1: *const MatPointer,
+            src2: Scalar,
+            dst: *const MatPointer,
+            scale: f64,
+            dtype: i32,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_divide(
+            src1: *const MatPointer,
+            src2: *const MatPointer,
+            dst: *const MatPointer,
+            scale: f64,
+            dtype: i32,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_divide_scalar(
+            src1: *const MatPointer,
+            src2: Scalar,
+            dst: *const MatPointer,
+            scale: f64,
+            dtype: i32,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_absdiff(
+            src1: *const MatPointer,
+            src2: *const MatPointer,
+            dst: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_add_weighted(
+            src1: *const MatPointer,
+            alpha: f64,
+            src2: *const MatPointer,
+            beta: f64,
+            gamma: f64,
+            dst: *const MatPointer,
+            dtype: i32,
+        ) -> FFIResult<i32>;
+    }
+}
+
+pub(crate) fn mask_pointer(mask: Option<&Mat<u8, 1>>) -> *const MatPointer {
+    mask.map_or(std::ptr::null(), |mask| mask.pointer)
+}
+
+impl<T: Depth, const C: usize> Mat<T, C> {
+    /// `cv::add`, written as `U` and only where `mask` is non-zero.
+    pub fn add_masked<U: Depth>(
+        &self,
+        other: &Self,
+        mask: Option<&Mat<u8, 1>>,
+    ) -> Result<Mat<U, C>> {
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_add(
+                self.pointer,
+                other.pointer,
+                dst.pointer,
+                mask_pointer(mask),
+                U::DEPTH.bits(),
+            )
+        })?;
+        Ok(dst)
+    }
+
+    /// `cv::subtract`, written as `U` and only where `mask` is non-zero.
+    pub fn subtract_masked<U: Depth>(
+        &self,
+        other: &Self,
+        mask: Option<&Mat<u8, 1>>,
+    ) -> Result<Mat<U, C>> {
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_subtract(
+                self.pointer,
+                other.pointer,
+                dst.pointer,
+                mask_pointer(mask),
+                U::DEPTH.bits(),
+            )
+        })?;
+        Ok(dst)
+    }
+
+    /// `scale * self * other`, written as `U`.
+    pub fn multiply_scaled<U: Depth>(&self, other: &Self, scale: f64) -> Result<Mat<U, C>> {
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_multiply(
+                self.pointer,
+                other.pointer,
+                dst.pointer,
+                scale,
+                U::DEPTH.bits(),
+            )
+        })?;
+        Ok(dst)
+    }
+
+    /// `scale * self / other`, written as `U`. Division by zero yields zero for
+    /// integer `U` and follows IEEE 754, giving infinity or NaN, for float `U`.
+    pub fn divide_scaled<U: Depth>(&self, other: &Self, scale: f64) -> Result<Mat<U, C>> {
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_divide(
+                self.pointer,
+                other.pointer,
+                dst.pointer,
+                scale,
+                U::DEPTH.bits(),
+            )
+        })?;
+        Ok(dst)
+    }
+
+    /// `|self - other|` per element.
+    pub fn absdiff(&self, other: &Self) -> Result<Self> {
+        let dst = Mat::new()?;
+        Result::from(unsafe { ffi::cv_absdiff(self.pointer, other.pointer, dst.pointer) })?;
+        Ok(dst)
+    }
+
+    /// `self * alpha + other * beta + gamma`, written as `U`.
+    pub fn add_weighted<U: Depth>(
+        &self,
+        alpha: f64,
+        other: &Self,
+        beta: f64,
+        gamma: f64,
+    ) -> Result<Mat<U, C>> {
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_add_weighted(
+                self.pointer,
+                alpha,
+                other.pointer,
+                beta,
+                gamma,
+                dst.pointer,
+                U::DEPTH.bits(),
+            )
+        })?;
+        Ok(dst)
+    }
+}
+
+macro_rules! impl_arithm_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $mat_op:expr, $scalar_op:expr, $extra:expr, $fallible:literal) => {
+        impl<T: Depth, const C: usize> $trait<&Mat<T, C>> for &Mat<T, C> {
+            type Output = Mat<T, C>;
+
+            /// # Panics
+            ///
+            /// If the Mats differ in size or OpenCV fails otherwise.
+            #[doc = concat!("[`Mat::", $fallible, "`] returns the error instead.")]
+            fn $method(self, rhs: &Mat<T, C>) -> Mat<T, C> {
+                let dst = Mat::new().unwrap();
+                Result::from(unsafe {
+                    $mat_op(
+                        self.pointer,
+                        rhs.pointer,
+                        dst.pointer,
+                        $extra,
+                        T::DEPTH.bits(),
+                    )
+                })
+                .unwrap_or_else(|e| panic!("{}", e));
+                dst
+            }
+        }
+
+        impl<T: Depth, const C: usize> $trait<&Mat<T, C>> for Mat<T, C> {
+            type Output = Mat<T, C>;
+
+            fn $method(self, rhs: &Mat<T, C>) -> Mat<T, C> {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl<T: Depth, const C: usize> $trait<Mat<T, C>> for Mat<T, C> {
+            type Output = Mat<T, C>;
+
+            fn $method(self, rhs: Mat<T, C>) -> Mat<T, C> {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl<T: Depth, const C: usize> $trait<Scalar> for &Mat<T, C> {
+            type Output = Mat<T, C>;
+
+            fn $method(self, rhs: Scalar) -> Mat<T, C> {
+                let dst = Mat::new().unwrap();
+                Result::from(unsafe {
+                    $scalar_op(self.pointer, rhs, dst.pointer, $extra, T::DEPTH.bits())
+                })
+                .unwrap_or_else(|e| panic!("{}", e));
+                dst
+            }
+        }
+
+        impl<T: Depth, const C: usize> $trait<Scalar> for Mat<T, C> {
+            type Output = Mat<T, C>;
+
+            fn $method(self, rhs: Scalar) -> Mat<T, C> {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl<T: Depth, const C: usize> $assign_trait<&Mat<T, C>> for Mat<T, C> {
+            /// # Panics
+            ///
+            /// If the Mats differ in size or OpenCV fails otherwise.
+            #[doc = concat!("[`Mat::", $fallible, "`] returns the error instead.")]
+            fn $assign_method(&mut self, rhs: &Mat<T, C>) {
+                Result::from(unsafe {
+                    $mat_op(
+                        self.pointer,
+                        rhs.pointer,
+                        self.pointer,
+                        $extra,
+                        T::DEPTH.bits(),
+                    )
+                })
+                .unwrap_or_else(|e| panic!("{}", e));
+            }
+        }
+
+        impl<T: Depth, const C: usize> $assign_trait<Scalar> for Mat<T, C> {
+            fn $assign_method(&mut self, rhs: Scalar) {
+                Result::from(unsafe {
+                    $scalar_op(self.pointer, rhs, self.pointer, $extra, T::DEPTH.bits())
+                })
+                .unwrap_or_else(|e| panic!("{}", e));
+            }
+        }
+    };
+}
+
+impl_arithm_op!(
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    ffi::cv_add,
+    ffi::cv_add_scalar,
+    std::ptr::null(),
+    "add_masked"
+);
+impl_arithm_op!(
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    ffi::cv_subtract,
+    ffi::cv_subtract_scalar,
+    std::ptr::null(),
+    "subtract_masked"
+);
+impl_arithm_op!(
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    ffi::cv_multiply,
+    ffi::cv_multiply_scalar,
+    1.,
+    "multiply_scaled"
+);
+impl_arithm_op!(
+    Div,
+    div,
+    DivAssign,
+    div_assign,
+    ffi::cv_divide,
+    ffi::cv_divide_scalar,
+    1.,
+    "divide_scaled"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mat(data: &[u8]) -> Mat<u8, 1> {
+        Mat::<u8, 1>::from_shape_vec(1, data.len(), data).unwrap()
+    }
+
+    #[test]
+    fn add_test() {
+        let a = mat(&[200, 10, 0]);
+        let b = mat(&[100, 20, 0]);
+        assert_eq!((&a + &b).data(), &[255, 30, 0]);
+        assert_eq!((&a + Scalar::all(60.)).data(), &[255, 70, 60]);
+        let mut c = a.clone();
+        c += &b;
+        assert_eq!(c.data(), &[255, 30, 0]);
+        c += Scalar::all(1.);
+        assert_eq!(c.data(), &[255, 31, 1]);
+    }
+
+    #[test]
+    fn sub_test() {
+        let a = mat(&[50, 30, 0]);
+        let b = mat(&[100, 20, 0]);
+        assert_eq!((&a - &b).data(), &[0, 10, 0]);
+        assert_eq!((a - Scalar::all(40.)).data(), &[10, 0, 0]);
+    }
+
+    #[test]
+    fn mul_div_test() {
+        let a = mat(&[100, 10, 7]);
+        let b = mat(&[3, 2, 0]);
+        assert_eq!((&a * &b).data(), &[255, 20, 0]);
+        assert_eq!((&a / &b).data(), &[33, 5, 0]);
+        let mut c = a.clone();
+        c *= Scalar::all(2.);
+        assert_eq!(c.data(), &[200, 20, 14]);
+        c /= Scalar::all(4.);
+        assert_eq!(c.data(), &[50, 5, 4]);
+    }
+
+    #[test]
+    fn scaled_test() {
+        let a = mat(&[100, 10, 7]);
+        let b = mat(&[3, 2, 0]);
+        let product: Mat<f32, 1> = a.multiply_scaled(&b, 0.5).unwrap();
+        assert_eq!(product.data(), &[150., 10., 0.]);
+        let quotient: Mat<f64, 1> = a.divide_scaled(&b, 3.).unwrap();
+        assert_eq!(quotient.data(), &[100., 15., f64::INFINITY]);
+        let quotient: Mat<u16, 1> = a.divide_scaled(&b, 3.).unwrap();
+        assert_eq!(quotient.data(), &[100, 15, 0]);
+        let sum: Mat<i16, 1> = a.add_masked(&b, None).unwrap();
+        assert_eq!(sum.data(), &[103, 12, 7]);
+        let diff: Mat<i16, 1> = b.subtract_masked(&a, None).unwrap();
+        assert_eq!(diff.data(), &[-97, -8, -7]);
+    }
+
+    #[test]
+    fn mask_test() {
+        let a = mat(&[1, 2, 3]);
+        let b = mat(&[10, 20, 30]);
+        let mask = mat(&[255, 0, 255]);
+        let sum: Mat<u8, 1> = a.add_masked(&b, Some(&mask)).unwrap();
+        assert_eq!(sum.data(), &[11, 0, 33]);
+    }
+
+    #[test]
+    fn absdiff_test() {
+        let a = mat(&[50, 30, 0]);
+        let b = mat(&[100, 20, 0]);
+        assert_eq!(a.absdiff(&b).unwrap().data(), &[50, 10, 0]);
+    }
+
+    #[test]
+    fn add_weighted_test() {
+        let a = mat(&[100, 10, 0]);
+        let b = mat(&[0, 30, 255]);
+        let blended: Mat<f32, 1> = a.add_weighted(0.5, &b, 0.5, 1.).unwrap();
+        assert_eq!(blended.data(), &[51., 21., 128.5]);
+    }
+
+    #[test]
+    fn size_mismatch_test() {
+        let a = mat(&[1, 2, 3]);
+        let b = mat(&[1, 2]);
+        assert!(a.absdiff(&b).is_err());
+        assert!(a.add_masked::<u8>(&b, None).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn size_mismatch_panic_test() {
+        let a = mat(&[1, 2, 3]);
+        let b = mat(&[1, 2]);
+        let _ = &a + &b;
+    }
+}
@@ -1,59 +1,79 @@
-use super::Mat;
-use crate::result::Result;
-use ndarray::Dim;
+use super::{view::from_data, Depth, Mat, MatMut, MatRef};
+use crate::result::{Error, Result};
+use ndarray::{
+    Array2, Array3, ArrayBase, ArrayView2, ArrayView3, ArrayViewMut2, ArrayViewMut3, Data, Ix2,
+    Ix3, ShapeBuilder,
+};
+use std::ptr::NonNull;
 
-impl<T, const C: usize> From<&Mat<T, C>> for ndarray::Array<T, Dim<[usize; 3]>>
+impl<T, const C: usize> Mat<T, C> {
+    /// `(rows, cols, C)` with the row stride taken from the Mat step,
+    /// so views of non-continuous Mats are allowed.
+    fn array_shape(&self) -> (usize, usize, usize, *mut T) {
+        let rows = self.rows() as usize;
+        let cols = self.cols() as usize;
+        let step = self.step();
+        assert_eq!(step % std::mem::size_of::<T>(), 0, "unaligned Mat step");
+        let data = self.data_ptr() as *mut T;
+        let data = if data.is_null() {
+            NonNull::dangling().as_ptr()
+        } else {
+            data
+        };
+        (rows, cols, step / std::mem::size_of::<T>(), data)
+    }
+
+    /// Borrow the Mat buffer as a `(rows, cols, C)` array without copying.
+    pub fn as_array_view(&self) -> ArrayView3<'_, T> {
+        let (rows, cols, row_stride, data) = self.array_shape();
+        unsafe { ArrayView3::from_shape_ptr((rows, cols, C).strides((row_stride, C, 1)), data) }
+    }
+
+    pub fn as_array_view_mut(&mut self) -> ArrayViewMut3<'_, T> {
+        let (rows, cols, row_stride, data) = self.array_shape();
+        unsafe { ArrayViewMut3::from_shape_ptr((rows, cols, C).strides((row_stride, C, 1)), data) }
+    }
+
+    pub fn to_ndarray(&self) -> Array3<T>
+    where
+        T: Clone,
+    {
+        self.into()
+    }
+}
+
+impl<T, const C: usize> From<&Mat<T, C>> for Array3<T>
 where
     T: Clone,
 {
     fn from(mat: &Mat<T, C>) -> Self {
-        ndarray::Array::from_shape_vec(
-            (
-                mat.rows() as usize,
-                mat.cols() as usize,
-                mat.channels() as usize,
-            ),
-            mat.data().to_vec(),
-        )
-        .unwrap()
+        mat.as_array_view().to_owned()
     }
 }
 
-macro_rules! impl_mat_from_ndarray {
-    ($t:ty, $c:tt, $code:expr) => {
-        impl<'a> Mat<$t, $c> {
-            pub fn from_ndarray(array: &'a ndarray::Array<$t, Dim<[usize; 3]>>) -> Result<Self> {
-                let (rows, cols, _channels) = array.dim();
-                Self::from_shape_vec(rows, cols, array.as_slice().unwrap())
-            }
-        }
-    };
+impl<T: Depth, const C: usize> Mat<T, C> {
+    /// Copy a `(rows, cols, C)` array of any memory layout into a new Mat.
+    pub fn from_ndarray<S>(array: &ArrayBase<S, Ix3>) -> Result<Self>
+    where
+        S: Data<Elem = T>,
+    {
+        let array = array.as_standard_layout();
+        Ok(MatRef::from_array_view(array.view())?.clone())
+    }
 }
 
-impl_mat_from_ndarray!(u8, 1, DataTypes::CV_8U);
-impl_mat_from_ndarray!(u8, 2, DataTypes::CV_8U);
-impl_mat_from_ndarray!(u8, 3, DataTypes::CV_8U);
-impl_mat_from_ndarray!(i8, 1, DataTypes::CV_8S);
-impl_mat_from_ndarray!(i8, 2, DataTypes::CV_8S);
-impl_mat_from_ndarray!(i8, 3, DataTypes::CV_8S);
-impl_mat_from_ndarray!(u16, 1, DataTypes::CV_16U);
-impl_mat_from_ndarray!(u16, 2, DataTypes::CV_16U);
-impl_mat_from_ndarray!(u16, 3, DataTypes::CV_16U);
-impl_mat_from_ndarray!(i16, 1, DataTypes::CV_16S);
-impl_mat_from_ndarray!(i16, 2, DataTypes::CV_16S);
-impl_mat_from_ndarray!(i16, 3, DataTypes::CV_16S);
-impl_mat_from_ndarray!(i32, 1, DataTypes::CV_32S);
-impl_mat_from_ndarray!(i32, 2, DataTypes::CV_32S);
-impl_mat_from_ndarray!(i32, 3, DataTypes::CV_32S);
-impl_mat_from_ndarray!(f32, 1, DataTypes::CV_32F);
-impl_mat_from_ndarray!(f32, 2, DataTypes::CV_32F);
-impl_mat_from_ndarray!(f32, 3, DataTypes::CV_32F);
-impl_mat_from_ndarray!(f64, 1, DataTypes::CV_64F);
-impl_mat_from_ndarray!(f64, 2, DataTypes::CV_64F);
-impl_mat_from_ndarray!(f64, 3, DataTypes::CV_64F);
+impl<T> Mat<T, 1> {
+    pub fn as_array2_view(&self) -> ArrayView2<'_, T> {
+        let (rows, cols, row_stride, data) = self.array_shape();
+        unsafe { ArrayView2::from_shape_ptr((rows, cols).strides((row_stride, 1)), data) }
+    }
 
-impl<T, const C: usize> Mat<T, C> {
-    pub fn to_ndarray(&self) -> ndarray::Array<T, Dim<[usize; 3]>>
+    pub fn as_array2_view_mut(&mut self) -> ArrayViewMut2<'_, T> {
+        let (rows, cols, row_stride, data) = self.array_shape();
+        unsafe { ArrayViewMut2::from_shape_ptr((rows, cols).strides((row_stride, 1)), data) }
+    }
+
+    pub fn to_array2(&self) -> Array2<T>
     where
         T: Clone,
     {
@@ -61,9 +81,95 @@ impl<T, const C: usize> Mat<T, C> {
     }
 }
 
+impl<T> From<&Mat<T, 1>> for Array2<T>
+where
+    T: Clone,
+{
+    fn from(mat: &Mat<T, 1>) -> Self {
+        mat.as_array2_view().to_owned()
+    }
+}
+
+impl<T: Depth> Mat<T, 1> {
+    pub fn from_array2<S>(array: &ArrayBase<S, Ix2>) -> Result<Self>
+    where
+        S: Data<Elem = T>,
+    {
+        let array = array.as_standard_layout();
+        Ok(MatRef::from_array2_view(array.view())?.clone())
+    }
+}
+
+/// Row step in elements for a `(rows, cols, channels)` array whose pixels are
+/// packed and whose rows are evenly spaced, as `cv::Mat` requires.
+fn row_stride(dim: (usize, usize, usize), strides: &[isize]) -> Result<usize> {
+    let (rows, cols, channels) = dim;
+    let packed = cols * channels;
+    let channel_ok = channels <= 1 || strides[2] == 1;
+    let col_ok = cols <= 1 || strides[1] == channels as isize;
+    let row_ok = rows <= 1 || strides[0] >= packed as isize;
+    if !(channel_ok && col_ok && row_ok) {
+        return Err(Error::InvalidArgument(format!(
+            "array with strides {:?} is not contiguous within rows",
+            strides
+        )));
+    }
+    Ok(if rows <= 1 {
+        packed
+    } else {
+        strides[0] as usize
+    })
+}
+
+fn check_channels<const C: usize>(channels: usize) -> Result<()> {
+    if channels == C {
+        Ok(())
+    } else {
+        Err(Error::InvalidArgument(format!(
+            "array has {} channels, expected {}",
+            channels, C
+        )))
+    }
+}
+
+impl<'a, T: Depth, const C: usize> MatRef<'a, T, C> {
+    /// Borrow an ndarray buffer as a Mat without copying.
+    ///
+    /// The channel axis must have length `C` and each row must be packed;
+    /// rows themselves may be strided, as in a slice of a wider array.
+    pub fn from_array_view(view: ArrayView3<'a, T>) -> Result<Self> {
+        let (rows, cols, channels) = view.dim();
+        check_channels::<C>(channels)?;
+        let row_stride = row_stride(view.dim(), view.strides())?;
+        let pointer = from_data::<T, C>(rows, cols, row_stride, view.as_ptr() as *mut T)?;
+        Ok(Self::from_ptr(pointer))
+    }
+}
+
+impl<'a, T: Depth> MatRef<'a, T, 1> {
+    pub fn from_array2_view(view: ArrayView2<'a, T>) -> Result<Self> {
+        let (rows, cols) = view.dim();
+        let strides = [view.strides()[0], view.strides()[1], 1];
+        let row_stride = row_stride((rows, cols, 1), &strides)?;
+        let pointer = from_data::<T, 1>(rows, cols, row_stride, view.as_ptr() as *mut T)?;
+        Ok(Self::from_ptr(pointer))
+    }
+}
+
+impl<'a, T: Depth, const C: usize> MatMut<'a, T, C> {
+    pub fn from_array_view_mut(mut view: ArrayViewMut3<'a, T>) -> Result<Self> {
+        let (rows, cols, channels) = view.dim();
+        check_channels::<C>(channels)?;
+        let row_stride = row_stride(view.dim(), view.strides())?;
+        let pointer = from_data::<T, C>(rows, cols, row_stride, view.as_mut_ptr())?;
+        Ok(Self::from_ptr(pointer))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ndarray::{s, Array};
 
     #[test]
     fn convert_test() {
@@ -95,4 +201,85 @@ mod tests {
         );
         assert_eq!(dst.data(), src.data());
     }
+
+    #[test]
+    fn convert_4ch_test() {
+        let a = Array::from_shape_fn((3, 2, 4), |(r, c, ch)| (r * 8 + c * 4 + ch) as u16);
+        let mat = Mat::<u16, 4>::from_ndarray(&a).unwrap();
+        assert_eq!(mat.channels(), 4);
+        assert_eq!(mat.at(2, 1), &[20, 21, 22, 23]);
+        assert_eq!(mat.to_ndarray(), a);
+    }
+
+    #[test]
+    fn from_ndarray_channel_mismatch_test() {
+        let a = Array::<u8, _>::zeros((4, 4, 1));
+        assert!(matches!(
+            Mat::<u8, 3>::from_ndarray(&a),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn from_ndarray_layout_test() {
+        let a = Array::from_shape_fn((4, 3, 1), |(r, c, _)| (r * 3 + c) as u8);
+        let t = a.clone().permuted_axes([1, 0, 2]);
+        let mat = Mat::<u8, 1>::from_ndarray(&t).unwrap();
+        assert_eq!(mat.rows(), 3);
+        assert_eq!(mat.at(2, 1), &[5]);
+    }
+
+    #[test]
+    fn array_view_test() {
+        let mut src = Mat::mock_7x6_square_5x4();
+        let view = src.as_array_view();
+        assert_eq!(view.dim(), (6, 7, 3));
+        assert_eq!(view[[1, 1, 0]], 255);
+        assert_eq!(view.as_ptr(), src.data().as_ptr());
+        src.as_array_view_mut()[[0, 0, 2]] = 9;
+        assert_eq!(src.at(0, 0), &[0, 0, 9]);
+
+        let roi = src.col_range(2, 4).unwrap();
+        let view = roi.as_array_view();
+        assert_eq!(view.dim(), (6, 2, 3));
+        assert_eq!(view[[5, 1, 0]], *src.at(5, 3).first().unwrap());
+    }
+
+    #[test]
+    fn from_array_view_test() {
+        let mut a = Array::from_shape_fn((4, 6, 2), |(r, c, ch)| (r * 12 + c * 2 + ch) as f32);
+        {
+            let mat = MatRef::<f32, 2>::from_array_view(a.view()).unwrap();
+            assert_eq!(mat.at(1, 2), &[16., 17.]);
+            assert_eq!(mat.data().as_ptr(), a.as_ptr());
+        }
+        {
+            let slice = a.slice(s![1..3, 2..5, ..]);
+            let mat = MatRef::<f32, 2>::from_array_view(slice).unwrap();
+            assert_eq!(mat.cols(), 3);
+            assert_eq!(mat.at(1, 0), &[28., 29.]);
+        }
+        {
+            let mut mat = MatMut::<f32, 2>::from_array_view_mut(a.view_mut()).unwrap();
+            *mat.at_mut(0, 0) = [-1., -2.];
+        }
+        assert_eq!(a[[0, 0, 1]], -2.);
+
+        assert!(MatRef::<f32, 3>::from_array_view(a.view()).is_err());
+        let strided = a.slice(s![.., ..;2, ..]);
+        assert!(MatRef::<f32, 2>::from_array_view(strided).is_err());
+    }
+
+    #[test]
+    fn array2_test() {
+        let a = Array::from_shape_fn((3, 5), |(r, c)| (r * 5 + c) as i16);
+        let mat = Mat::<i16, 1>::from_array2(&a).unwrap();
+        assert_eq!(mat.at(2, 4), &[14]);
+        assert_eq!(mat.to_array2(), a);
+        assert_eq!(mat.as_array2_view(), a.view());
+        let view = MatRef::from_array2_view(a.t()).err();
+        assert!(view.is_some());
+        let mat = MatRef::<i16, 1>::from_array2_view(a.slice(s![1.., 1..4])).unwrap();
+        assert_eq!(mat.at(0, 0), &[6]);
+    }
 }
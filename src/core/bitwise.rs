@@ -0,0 +1,176 @@
+//! Per-bit operations and masking. Every `mask` is a `Mat<u8, 1>` of the same size;
+//! the `bitwise_*` outputs are zero where it is zero.
+use super::{arithm::mask_pointer, Depth, Mat, Scalar};
+use crate::result::Result;
+
+mod ffi {
+    use crate::{
+        core::{MatPointer, Scalar},
+        ffi::FFIResult,
+    };
+
+    #[link(name = "rxcv", kind = "static")]
+    extern "C" {
+        pub(super) fn cv_bitwise_and(
+            src1: *const MatPointer,
+            src2: *const MatPointer,
+            dst: *const MatPointer,
+            mask: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_bitwise_or(
+            src1: *const MatPointer,
+            src2: *const MatPointer,
+            dst: *const MatPointer,
+            mask: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_bitwise_xor(
+            src1: *const MatPointer,
+            src2: *const MatPointer,
+            dst: *const MatPointer,
+            mask: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_bitwise_not(
+            src: *const MatPointer,
+            dst: *const MatPointer,
+            mask: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_in_range(
+            src: *const MatPointer,
+            lower: Scalar,
+            upper: Scalar,
+            dst: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_mat_copy_to_masked(
+            src: *const MatPointer,
+            dst: *const MatPointer,
+            mask: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_mat_set_to(
+            src: *const MatPointer,
+            value: Scalar,
+            mask: *const MatPointer,
+        ) -> FFIResult<i32>;
+    }
+}
+
+impl<T: Depth, const C: usize> Mat<T, C> {
+    pub fn bitwise_and(&self, other: &Self, mask: Option<&Mat<u8, 1>>) -> Result<Self> {
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_bitwise_and(self.pointer, other.pointer, dst.pointer, mask_pointer(mask))
+        })?;
+        Ok(dst)
+    }
+
+    pub fn bitwise_or(&self, other: &Self, mask: Option<&Mat<u8, 1>>) -> Result<Self> {
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_bitwise_or(self.pointer, other.pointer, dst.pointer, mask_pointer(mask))
+        })?;
+        Ok(dst)
+    }
+
+    pub fn bitwise_xor(&self, other: &Self, mask: Option<&Mat<u8, 1>>) -> Result<Self> {
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_bitwise_xor(self.pointer, other.pointer, dst.pointer, mask_pointer(mask))
+        })?;
+        Ok(dst)
+    }
+
+    pub fn bitwise_not(&self, mask: Option<&Mat<u8, 1>>) -> Result<Self> {
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_bitwise_not(self.pointer, dst.pointer, mask_pointer(mask))
+        })?;
+        Ok(dst)
+    }
+
+    /// 255 where every channel lies in `lower..=upper`, 0 elsewhere.
+    pub fn in_range(&self, lower: Scalar, upper: Scalar) -> Result<Mat<u8, 1>> {
+        let dst = Mat::new()?;
+        Result::from(unsafe { ffi::cv_in_range(self.pointer, lower, upper, dst.pointer) })?;
+        Ok(dst)
+    }
+
+    /// Copy the pixels selected by `mask` into `dst`, leaving the others untouched.
+    /// `dst` is reallocated and zeroed first if its size differs.
+    pub fn copy_to_masked(&self, dst: &mut Self, mask: &Mat<u8, 1>) -> Result<()> {
+        Result::from(unsafe {
+            ffi::cv_mat_copy_to_masked(self.pointer, dst.pointer, mask.pointer)
+        })?;
+        Ok(())
+    }
+
+    /// Set the pixels selected by `mask`, or every pixel, to `value`.
+    pub fn set_to(&mut self, value: Scalar, mask: Option<&Mat<u8, 1>>) -> Result<()> {
+        Result::from(unsafe { ffi::cv_mat_set_to(self.pointer, value, mask_pointer(mask)) })?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::imgproc::ThresholdTypes;
+
+    fn mat(data: &[u8]) -> Mat<u8, 1> {
+        Mat::from_shape_vec(1, data.len(), data).unwrap()
+    }
+
+    #[test]
+    fn bitwise_test() {
+        let a = mat(&[0b1100, 0b1010, 0xff]);
+        let b = mat(&[0b1010, 0b1010, 0x0f]);
+        assert_eq!(
+            a.bitwise_and(&b, None).unwrap().data(),
+            &[0b1000, 0b1010, 0x0f]
+        );
+        assert_eq!(
+            a.bitwise_or(&b, None).unwrap().data(),
+            &[0b1110, 0b1010, 0xff]
+        );
+        assert_eq!(a.bitwise_xor(&b, None).unwrap().data(), &[0b0110, 0, 0xf0]);
+        assert_eq!(b.bitwise_not(None).unwrap().data(), &[0xf5, 0xf5, 0xf0]);
+        let mask = mat(&[1, 0, 1]);
+        assert_eq!(
+            a.bitwise_or(&b, Some(&mask)).unwrap().data(),
+            &[0b1110, 0, 0xff]
+        );
+        assert_eq!(b.bitwise_not(Some(&mask)).unwrap().data(), &[0xf5, 0, 0xf0]);
+    }
+
+    #[test]
+    fn in_range_test() {
+        let hsv = Mat::mock_7x6_square_5x4().cvt_color_bgr2hsv().unwrap();
+        let lower = Scalar::new(0., 0., 200., 0.);
+        let upper = Scalar::new(180., 30., 255., 0.);
+        let mask = hsv.in_range(lower, upper).unwrap();
+        assert_eq!(mask.at(0, 0), &[0]);
+        assert_eq!(mask.at(1, 1), &[255]);
+        assert_eq!(mask.count_non_zero(None).unwrap(), 20);
+    }
+
+    #[test]
+    fn mask_combination_test() {
+        let gray = Mat::mock_7x6_square_2x4_2x3().cvt_color_bgr2gray().unwrap();
+        let (_, left) = gray
+            .col_range(0, 3)
+            .unwrap()
+            .threshold(127, 255, ThresholdTypes::THRESH_BINARY)
+            .unwrap();
+        let mut src = Mat::<u8, 3>::zeros(6, 7).unwrap();
+        src.set_to(Scalar::new(1., 2., 3., 0.), None).unwrap();
+        let mut dst = Mat::<u8, 3>::zeros(6, 3).unwrap();
+        src.col_range(0, 3)
+            .unwrap()
+            .copy_to_masked(&mut dst, &left)
+            .unwrap();
+        assert_eq!(dst.at(0, 0), &[0, 0, 0]);
+        assert_eq!(dst.at(1, 1), &[1, 2, 3]);
+
+        dst.set_to(Scalar::all(9.), Some(&left)).unwrap();
+        assert_eq!(dst.at(4, 2), &[9, 9, 9]);
+        assert_eq!(dst.at(5, 2), &[0, 0, 0]);
+    }
+}
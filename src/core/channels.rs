@@ -0,0 +1,163 @@
+use super::{Depth, Mat};
+use crate::result::{Error, Result};
+
+mod ffi {
+    use crate::{core::MatPointer, ffi::FFIResult};
+
+    #[link(name = "rxcv", kind = "static")]
+    extern "C" {
+        pub(super) fn cv_split(
+            src: *const MatPointer,
+            dst: *const *const MatPointer,
+            count: usize,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_merge(
+            src: *const *const MatPointer,
+            count: usize,
+            dst: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_extract_channel(
+            src: *const MatPointer,
+            dst: *const MatPointer,
+            coi: i32,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_insert_channel(
+            src: *const MatPointer,
+            dst: *const MatPointer,
+            coi: i32,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_mix_channels(
+            src: *const MatPointer,
+            dst: *const MatPointer,
+            dtype: i32,
+            from_to: *const i32,
+            npairs: usize,
+        ) -> FFIResult<i32>;
+    }
+}
+
+fn check_channel<const C: usize>(channel: usize) -> Result<()> {
+    if channel < C {
+        Ok(())
+    } else {
+        Err(Error::InvalidArgument(format!(
+            "channel {} out of range for Mat with {} channels",
+            channel, C
+        )))
+    }
+}
+
+impl<T: Depth, const C: usize> Mat<T, C> {
+    /// One single-channel Mat per channel (`cv::split`).
+    pub fn split(&self) -> Result<[Mat<T, 1>; C]> {
+        let channels = (0..C).map(|_| Mat::new()).collect::<Result<Vec<_>>>()?;
+        let pointers = channels.iter().map(|m| m.pointer).collect::<Vec<_>>();
+        Result::from(unsafe { ffi::cv_split(self.pointer, pointers.as_ptr(), C) })?;
+        Ok(channels.try_into().unwrap_or_else(|_| unreachable!()))
+    }
+
+    /// Interleave `C` single-channel Mats of equal size (`cv::merge`).
+    pub fn merge(channels: [&Mat<T, 1>; C]) -> Result<Self> {
+        let dst = Mat::new()?;
+        let pointers = channels.map(|m| m.pointer);
+        Result::from(unsafe { ffi::cv_merge(pointers.as_ptr(), C, dst.pointer) })?;
+        Ok(dst)
+    }
+
+    pub fn extract_channel(&self, channel: usize) -> Result<Mat<T, 1>> {
+        check_channel::<C>(channel)?;
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_extract_channel(self.pointer, dst.pointer, channel as i32)
+        })?;
+        Ok(dst)
+    }
+
+    /// Overwrite `channel` of `self` with `src`, which must have the same size.
+    pub fn insert_channel(&mut self, channel: usize, src: &Mat<T, 1>) -> Result<()> {
+        check_channel::<C>(channel)?;
+        Result::from(unsafe { ffi::cv_insert_channel(src.pointer, self.pointer, channel as i32) })?;
+        Ok(())
+    }
+
+    /// Channel `i` of the result is channel `from[i]` of `self` (`cv::mixChannels`).
+    ///
+    /// `[2, 1, 0]` turns BGR into RGB, `[3, 2, 1, 0]` turns BGRA into ARGB.
+    pub fn mix_channels<const D: usize>(&self, from: [usize; D]) -> Result<Mat<T, D>> {
+        let mut from_to = Vec::with_capacity(D * 2);
+        for (to, &from) in from.iter().enumerate() {
+            check_channel::<C>(from)?;
+            from_to.extend([from as i32, to as i32]);
+        }
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_mix_channels(
+                self.pointer,
+                dst.pointer,
+                Mat::<T, D>::TYPE,
+                from_to.as_ptr(),
+                D,
+            )
+        })?;
+        Ok(dst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::imgproc::ThresholdTypes;
+
+    fn bgr() -> Mat<u8, 3> {
+        let data = (0..2 * 2 * 3).map(|v| v as u8).collect::<Vec<_>>();
+        Mat::from_shape_vec(2, 2, &data).unwrap()
+    }
+
+    #[test]
+    fn split_merge_test() {
+        let src = bgr();
+        let [b, g, r] = src.split().unwrap();
+        assert_eq!(b.data(), &[0, 3, 6, 9]);
+        assert_eq!(g.data(), &[1, 4, 7, 10]);
+        assert_eq!(r.data(), &[2, 5, 8, 11]);
+        let rgb = Mat::merge([&r, &g, &b]).unwrap();
+        assert_eq!(rgb.at(0, 1), &[5, 4, 3]);
+    }
+
+    #[test]
+    fn extract_insert_channel_test() {
+        let mut src = bgr();
+        let g = src.extract_channel(1).unwrap();
+        assert_eq!(g.data(), &[1, 4, 7, 10]);
+        src.insert_channel(0, &g).unwrap();
+        assert_eq!(src.at(1, 1), &[10, 10, 11]);
+        assert!(matches!(
+            src.extract_channel(3),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn mix_channels_test() {
+        let src = bgr();
+        let rgb = src.mix_channels([2, 1, 0]).unwrap();
+        assert_eq!(rgb.at(1, 0), &[8, 7, 6]);
+        let bgra = src.cvt_color_bgr2bgra().unwrap();
+        let argb = bgra.mix_channels([3, 2, 1, 0]).unwrap();
+        assert_eq!(argb.at(0, 1), &[255, 5, 4, 3]);
+        let rr = src.mix_channels([2, 2]).unwrap();
+        assert_eq!(rr.at(0, 0), &[2, 2]);
+        assert!(src.mix_channels([3]).is_err());
+    }
+
+    #[test]
+    fn threshold_value_channel_test() {
+        let hsv = Mat::mock_7x6_square_5x4().cvt_color_bgr2hsv().unwrap();
+        let v = hsv.extract_channel(2).unwrap();
+        let (_, mask) = v
+            .threshold(127, 255, ThresholdTypes::THRESH_BINARY)
+            .unwrap();
+        assert_eq!(mask.at(0, 0), &[0]);
+        assert_eq!(mask.at(1, 1), &[255]);
+    }
+}
@@ -45,6 +45,9 @@ bitflags! {
     }
 }
 
+/// Maximum number of channels of a Mat element.
+pub const CV_CN_MAX: usize = 512;
+
 pub(super) const fn make_type(depth: DataTypes, ch: i32) -> i32 {
     let channel_shift = 3;
     let depth_max = 1 << channel_shift;
@@ -53,6 +56,12 @@ pub(super) const fn make_type(depth: DataTypes, ch: i32) -> i32 {
     mat_depth + ((ch - 1) << channel_shift)
 }
 
+/// Name such as `CV_8UC3`, for any channel count.
+pub(crate) fn type_name(r#type: i32) -> String {
+    const DEPTHS: [&str; 8] = ["8U", "8S", "16U", "16S", "32S", "32F", "64F", "16F"];
+    format!("CV_{}C{}", DEPTHS[(r#type & 7) as usize], (r#type >> 3) + 1)
+}
+
 bitflags! {
     pub struct BorderTypes: i32 {
         const BORDER_CONSTANT = 0;
@@ -67,42 +76,120 @@ bitflags! {
     }
 }
 
-#[repr(C)]
-pub struct PointBase<T> {
-    pub x: T,
-    pub y: T,
+bitflags! {
+    pub struct FlipCode: i32 {
+        /// Upside down, around the x-axis.
+        const FLIP_VERTICAL = 0;
+        /// Mirrored, around the y-axis.
+        const FLIP_HORIZONTAL = 1;
+        const FLIP_BOTH = -1;
+    }
+}
+
+bitflags! {
+    pub struct RotateFlags: i32 {
+        const ROTATE_90_CLOCKWISE = 0;
+        const ROTATE_180 = 1;
+        const ROTATE_90_COUNTERCLOCKWISE = 2;
+    }
 }
 
-pub type Point2i = PointBase<i32>;
-pub type Point2f = PointBase<f32>;
-pub type Point = Point2i;
+bitflags! {
+    pub struct DecompTypes: i32 {
+        const DECOMP_LU = 0;
+        const DECOMP_SVD = 1;
+        const DECOMP_EIG = 2;
+        const DECOMP_CHOLESKY = 3;
+        const DECOMP_QR = 4;
+        const DECOMP_NORMAL = 16;
+    }
+}
 
-impl Default for Point {
-    fn default() -> Self {
-        Self { x: -1, y: -1 }
+bitflags! {
+    pub struct DftFlags: i32 {
+        const DFT_INVERSE = 1;
+        const DFT_SCALE = 2;
+        const DFT_ROWS = 4;
+        const DFT_COMPLEX_OUTPUT = 16;
+        const DFT_REAL_OUTPUT = 32;
+        const DFT_COMPLEX_INPUT = 64;
+        const DCT_INVERSE = 1;
+        const DCT_ROWS = 4;
     }
 }
 
-#[repr(C)]
-pub struct SizeT<T> {
-    pub width: T,
-    pub height: T,
+bitflags! {
+    pub struct FileStorageMode: i32 {
+        const READ = 0;
+        const WRITE = 1;
+        const APPEND = 2;
+        const MEMORY = 4;
+        const FORMAT_MASK = 7 << 3;
+        const FORMAT_AUTO = 0;
+        const FORMAT_XML = 1 << 3;
+        const FORMAT_YAML = 2 << 3;
+        const FORMAT_JSON = 3 << 3;
+        const BASE64 = 64;
+        const WRITE_BASE64 = Self::BASE64.bits | Self::WRITE.bits;
+    }
 }
 
-impl<T> Default for SizeT<T>
-where
-    T: Default,
-{
-    fn default() -> Self {
-        Self {
-            width: T::default(),
-            height: T::default(),
-        }
+bitflags! {
+    pub struct FileNodeType: i32 {
+        const NONE = 0;
+        const INT = 1;
+        const REAL = 2;
+        const STR = 3;
+        const SEQ = 4;
+        const MAP = 5;
+        const TYPE_MASK = 7;
+        const FLOW = 8;
+        const UNIFORM = 8;
+        const EMPTY = 16;
+        const NAMED = 32;
     }
 }
 
-pub type Size2i = SizeT<i32>;
-pub type Size = Size2i;
+bitflags! {
+    pub struct FormatStyles: i32 {
+        const FMT_DEFAULT = 0;
+        const FMT_MATLAB = 1;
+        const FMT_CSV = 2;
+        const FMT_PYTHON = 3;
+        const FMT_NUMPY = 4;
+        const FMT_C = 5;
+    }
+}
+
+bitflags! {
+    pub struct GemmFlags: i32 {
+        const GEMM_1_T = 1;
+        const GEMM_2_T = 2;
+        const GEMM_3_T = 4;
+    }
+}
+
+bitflags! {
+    pub struct SVDFlags: i32 {
+        const MODIFY_A = 1;
+        const NO_UV = 2;
+        const FULL_UV = 4;
+    }
+}
+
+bitflags! {
+    pub struct NormTypes: i32 {
+        const NORM_INF = 1;
+        const NORM_L1 = 2;
+        const NORM_L2 = 4;
+        const NORM_L2SQR = 5;
+        const NORM_HAMMING = 6;
+        const NORM_HAMMING2 = 7;
+        const NORM_TYPE_MASK = 7;
+        const NORM_RELATIVE = 8;
+        const NORM_MINMAX = 32;
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -111,6 +198,8 @@ mod tests {
     #[test]
     fn make_type_test() {
         assert_eq!(make_type(DataTypes::CV_16U, 3), 18);
+        assert_eq!(type_name(18), "CV_16UC3");
+        assert_eq!(type_name(make_type(DataTypes::CV_16F, 7)), "CV_16FC7");
     }
 
     #[test]
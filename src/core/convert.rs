@@ -0,0 +1,83 @@
+use super::{Depth, Mat};
+use crate::result::Result;
+
+mod ffi {
+    use crate::{core::MatPointer, ffi::FFIResult};
+
+    #[link(name = "rxcv", kind = "static")]
+    extern "C" {
+        pub(super) fn cv_mat_convert_to(
+            src: *const MatPointer,
+            dst: *const MatPointer,
+            rtype: i32,
+            alpha: f64,
+            beta: f64,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_convert_scale_abs(
+            src: *const MatPointer,
+            dst: *const MatPointer,
+            alpha: f64,
+            beta: f64,
+        ) -> FFIResult<i32>;
+    }
+}
+
+impl<T: Depth, const C: usize> Mat<T, C> {
+    /// `saturate_cast<U>(self * alpha + beta)` per element, via `cv::Mat::convertTo`.
+    pub fn convert_to<U: Depth>(&self, alpha: f64, beta: f64) -> Result<Mat<U, C>> {
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_mat_convert_to(self.pointer, dst.pointer, U::DEPTH.bits(), alpha, beta)
+        })?;
+        Ok(dst)
+    }
+
+    /// `saturate_cast<u8>(|self * alpha + beta|)`, typically used to visualise signed
+    /// derivatives such as the output of Sobel or Laplacian.
+    pub fn convert_scale_abs(&self, alpha: f64, beta: f64) -> Result<Mat<u8, C>> {
+        let dst = Mat::new()?;
+        Result::from(unsafe { ffi::cv_convert_scale_abs(self.pointer, dst.pointer, alpha, beta) })?;
+        Ok(dst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{imgproc::Sobel, BorderTypes};
+
+    #[test]
+    fn convert_to_test() {
+        let src = Mat::mock_7x6_square_5x4();
+        let dst: Mat<f32, 3> = src.convert_to(1. / 255., 0.).unwrap();
+        assert_eq!(dst.channels(), 3);
+        assert_eq!(dst.rows(), src.rows());
+        assert_eq!(dst.cols(), src.cols());
+        assert!(dst.data().iter().all(|&v| v == 0. || v == 1.));
+        let back: Mat<u8, 3> = dst.convert_to(255., 0.).unwrap();
+        assert_eq!(back.data(), src.data());
+    }
+
+    #[test]
+    fn convert_to_saturate_test() {
+        let data = [-10., 0.4, 300.];
+        let src = Mat::<f64, 1>::from_shape_vec(1, 3, &data).unwrap();
+        let dst: Mat<u8, 1> = src.convert_to(1., 0.).unwrap();
+        assert_eq!(dst.data(), &[0, 0, 255]);
+        let dst: Mat<i16, 1> = src.convert_to(2., 1.).unwrap();
+        assert_eq!(dst.data(), &[-19, 2, 601]);
+    }
+
+    #[test]
+    fn convert_scale_abs_test() {
+        let src = Mat::mock_7x6_square_5x4().cvt_color_bgr2gray().unwrap();
+        let dx: Mat<i16, 1> = src
+            .sobel(1, 0, 3, 1., 0., BorderTypes::BORDER_DEFAULT)
+            .unwrap();
+        assert!(dx.data().iter().any(|&v| v < 0));
+        let vis = dx.convert_scale_abs(1., 0.).unwrap();
+        assert_eq!(vis.rows(), src.rows());
+        assert!(vis.data().iter().any(|&v| v > 0));
+        assert!(vis.encode_png().is_ok());
+    }
+}
@@ -0,0 +1,245 @@
+//! Fourier and cosine transforms. Complex values are two-channel `(re, im)` Mats.
+use super::{Depth, DftFlags, Mat};
+use crate::result::{Error, Result};
+
+mod ffi {
+    use crate::{core::MatPointer, ffi::FFIResult};
+
+    #[link(name = "rxcv", kind = "static")]
+    extern "C" {
+        pub(super) fn cv_dft(
+            src: *const MatPointer,
+            dst: *const MatPointer,
+            flags: i32,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_dct(
+            src: *const MatPointer,
+            dst: *const MatPointer,
+            flags: i32,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_mul_spectrums(
+            a: *const MatPointer,
+            b: *const MatPointer,
+            c: *const MatPointer,
+            flags: i32,
+            conj_b: bool,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_get_optimal_dft_size(vecsize: i32) -> FFIResult<i32>;
+        pub(super) fn cv_shift_quadrants(
+            src: *const MatPointer,
+            dst: *const MatPointer,
+            inverse: bool,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_log_magnitude(
+            src: *const MatPointer,
+            dst: *const MatPointer,
+        ) -> FFIResult<i32>;
+    }
+}
+
+/// Smallest size `>= n` that the DFT handles efficiently (a product of 2, 3 and 5).
+pub fn get_optimal_dft_size(n: usize) -> Result<usize> {
+    let size = Result::from(unsafe { ffi::cv_get_optimal_dft_size(n as i32) })?;
+    Ok(size as usize)
+}
+
+impl<T: Depth, const C: usize> Mat<T, C> {
+    fn shift_quadrants(&self, inverse: bool) -> Result<Self> {
+        let dst = Mat::new()?;
+        Result::from(unsafe { ffi::cv_shift_quadrants(self.pointer, dst.pointer, inverse) })?;
+        Ok(dst)
+    }
+
+    /// Swap quadrants so that the zero frequency moves to the center, as `numpy.fft.fftshift`.
+    pub fn fft_shift(&self) -> Result<Self> {
+        self.shift_quadrants(false)
+    }
+
+    /// Undo [`fft_shift`](Self::fft_shift), which differs from it for odd sizes.
+    pub fn ifft_shift(&self) -> Result<Self> {
+        self.shift_quadrants(true)
+    }
+}
+
+fn dft<T: Depth, const C: usize, const D: usize>(
+    src: &Mat<T, C>,
+    flags: DftFlags,
+) -> Result<Mat<T, D>> {
+    let dst = Mat::<T, D>::new()?;
+    Result::from(unsafe { ffi::cv_dft(src.pointer, dst.pointer, flags.bits()) })?;
+    // The output channel count follows the flags; never hand out a mistyped Mat.
+    if dst.mat_type() != Mat::<T, D>::TYPE {
+        return Err(Error::TypeMismatch {
+            expected: Mat::<T, D>::TYPE,
+            actual: dst.mat_type(),
+        });
+    }
+    Ok(dst)
+}
+
+/// Flags that decide the direction or the output channel count, which the
+/// wrappers below fix themselves.
+const DFT_DIRECTION: DftFlags = DftFlags::from_bits_truncate(
+    DftFlags::DFT_INVERSE.bits()
+        | DftFlags::DFT_REAL_OUTPUT.bits()
+        | DftFlags::DFT_COMPLEX_OUTPUT.bits(),
+);
+
+fn dct<T: Depth>(src: &Mat<T, 1>, flags: DftFlags) -> Result<Mat<T, 1>> {
+    let dst = Mat::new()?;
+    Result::from(unsafe { ffi::cv_dct(src.pointer, dst.pointer, flags.bits()) })?;
+    Ok(dst)
+}
+
+macro_rules! impl_dft {
+    ($t:ty) => {
+        impl Mat<$t, 1> {
+            /// Full complex spectrum of a real Mat.
+            pub fn dft(&self, flags: DftFlags) -> Result<Mat<$t, 2>> {
+                dft(self, flags - DFT_DIRECTION | DftFlags::DFT_COMPLEX_OUTPUT)
+            }
+
+            /// Discrete cosine transform. Sizes other than 1 must be even.
+            pub fn dct(&self, flags: DftFlags) -> Result<Self> {
+                dct(self, flags)
+            }
+
+            pub fn idct(&self, flags: DftFlags) -> Result<Self> {
+                dct(self, flags | DftFlags::DCT_INVERSE)
+            }
+        }
+
+        impl Mat<$t, 2> {
+            pub fn dft(&self, flags: DftFlags) -> Result<Self> {
+                dft(self, flags - DFT_DIRECTION)
+            }
+
+            /// Inverse transform. Pass `DFT_SCALE` to divide by the number of elements.
+            pub fn idft(&self, flags: DftFlags) -> Result<Self> {
+                dft(self, flags - DFT_DIRECTION | DftFlags::DFT_INVERSE)
+            }
+
+            /// Inverse transform of a conjugate-symmetric spectrum, such as one
+            /// produced from a real Mat.
+            pub fn idft_real(&self, flags: DftFlags) -> Result<Mat<$t, 1>> {
+                dft(
+                    self,
+                    flags - DFT_DIRECTION | DftFlags::DFT_INVERSE | DftFlags::DFT_REAL_OUTPUT,
+                )
+            }
+
+            /// Per-element product of two spectra, conjugating `other` if `conj_b` is set.
+            /// Only `DFT_ROWS` is meaningful in `flags`.
+            pub fn mul_spectrums(
+                &self,
+                other: &Self,
+                flags: DftFlags,
+                conj_b: bool,
+            ) -> Result<Self> {
+                let dst = Mat::new()?;
+                Result::from(unsafe {
+                    ffi::cv_mul_spectrums(
+                        self.pointer,
+                        other.pointer,
+                        dst.pointer,
+                        flags.bits(),
+                        conj_b,
+                    )
+                })?;
+                Ok(dst)
+            }
+
+            /// `ln(1 + |z|)`, the usual way to display a spectrum.
+            pub fn log_magnitude(&self) -> Result<Mat<$t, 1>> {
+                let dst = Mat::new()?;
+                Result::from(unsafe { ffi::cv_log_magnitude(self.pointer, dst.pointer) })?;
+                Ok(dst)
+            }
+        }
+    };
+}
+
+impl_dft!(f32);
+impl_dft!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).abs() < 1e-9, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn dft_test() {
+        let src = Mat::<f64, 1>::from_shape_vec(1, 4, &[1., 2., 3., 4.]).unwrap();
+        let spectrum = src.dft(DftFlags::empty()).unwrap();
+        assert_eq!(spectrum.cols(), 4);
+        assert_close(spectrum.data(), &[10., 0., -2., 2., -2., 0., -2., -2.]);
+        let back = spectrum.idft_real(DftFlags::DFT_SCALE).unwrap();
+        assert_close(back.data(), src.data());
+
+        let complex = spectrum.dft(DftFlags::empty()).unwrap();
+        let back = complex.idft(DftFlags::DFT_SCALE).unwrap();
+        assert_close(back.data(), spectrum.data());
+    }
+
+    #[test]
+    fn dft_flags_test() {
+        let src = Mat::<f32, 1>::from_shape_vec(1, 4, &[1., 2., 3., 4.]).unwrap();
+        let spectrum = src.dft(DftFlags::DFT_INVERSE).unwrap();
+        assert_eq!(spectrum.channels(), 2);
+        let flags = DftFlags::DFT_INVERSE | DftFlags::DFT_REAL_OUTPUT;
+        assert_eq!(spectrum.dft(flags).unwrap().channels(), 2);
+        assert_eq!(spectrum.idft(flags).unwrap().channels(), 2);
+        assert_eq!(spectrum.idft_real(flags).unwrap().channels(), 1);
+    }
+
+    #[test]
+    fn dct_test() {
+        let src = Mat::<f32, 1>::from_shape_vec(1, 4, &[1., 1., 1., 1.]).unwrap();
+        let dst = src.dct(DftFlags::empty()).unwrap();
+        assert!((dst.data()[0] - 2.).abs() < 1e-6);
+        assert!(dst.data()[1..].iter().all(|v| v.abs() < 1e-6));
+        let back = dst.idct(DftFlags::empty()).unwrap();
+        assert!(back.data().iter().all(|v| (v - 1.).abs() < 1e-6));
+    }
+
+    #[test]
+    fn mul_spectrums_test() {
+        let a = Mat::<f32, 2>::from_shape_vec(1, 1, &[1., 2.]).unwrap();
+        let b = Mat::<f32, 2>::from_shape_vec(1, 1, &[3., 4.]).unwrap();
+        let c = a.mul_spectrums(&b, DftFlags::empty(), false).unwrap();
+        assert_eq!(c.data(), &[-5., 10.]);
+        let c = a.mul_spectrums(&b, DftFlags::empty(), true).unwrap();
+        assert_eq!(c.data(), &[11., 2.]);
+    }
+
+    #[test]
+    fn get_optimal_dft_size_test() {
+        assert_eq!(get_optimal_dft_size(64).unwrap(), 64);
+        assert_eq!(get_optimal_dft_size(13).unwrap(), 15);
+        assert_eq!(get_optimal_dft_size(97).unwrap(), 100);
+    }
+
+    #[test]
+    fn fft_shift_test() {
+        let src = Mat::<u8, 1>::from_shape_vec(1, 5, &[0, 1, 2, 3, 4]).unwrap();
+        let shifted = src.fft_shift().unwrap();
+        assert_eq!(shifted.data(), &[3, 4, 0, 1, 2]);
+        assert_eq!(shifted.ifft_shift().unwrap().data(), src.data());
+
+        let src = Mat::<u8, 2>::from_shape_vec(2, 2, &[1, 1, 2, 2, 3, 3, 4, 4]).unwrap();
+        assert_eq!(src.fft_shift().unwrap().data(), &[4, 4, 3, 3, 2, 2, 1, 1]);
+    }
+
+    #[test]
+    fn log_magnitude_test() {
+        let src = Mat::<f64, 2>::from_shape_vec(1, 2, &[3., 4., 0., 0.]).unwrap();
+        let dst = src.log_magnitude().unwrap();
+        assert_close(dst.data(), &[6f64.ln(), 0.]);
+    }
+}
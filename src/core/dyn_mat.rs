@@ -0,0 +1,217 @@
+use super::{DataTypes, Depth, Mat, MatPointer};
+use crate::result::{Error, Result};
+use std::mem::ManuallyDrop;
+
+mod ffi {
+    use crate::{core::MatPointer, ffi::FFIResult};
+
+    #[link(name = "rxcv", kind = "static")]
+    extern "C" {
+        pub(super) fn cv_mat_clone(pointer: *const MatPointer) -> FFIResult<*const MatPointer>;
+        pub(super) fn cv_mat_type(pointer: *const MatPointer) -> i32;
+        pub(super) fn cv_mat_cols(pointer: *const MatPointer) -> i32;
+        pub(super) fn cv_mat_rows(pointer: *const MatPointer) -> i32;
+        pub(super) fn cv_mat_channels(pointer: *const MatPointer) -> i32;
+        pub(super) fn cv_mat_dims(pointer: *const MatPointer) -> i32;
+        pub(super) fn cv_release_mat(pointer: *const MatPointer);
+    }
+}
+
+/// A Mat whose element type is only known at runtime, such as an image decoded
+/// with `IMREAD_UNCHANGED`.
+///
+/// Convert it with `Mat::<T, C>::try_from`, or dispatch over every supported type
+/// with [`match_type!`](crate::match_type).
+pub struct DynMat {
+    pub(crate) pointer: *const MatPointer,
+}
+
+impl DynMat {
+    pub(crate) fn from_ptr(pointer: *const MatPointer) -> Self {
+        Self { pointer }
+    }
+
+    /// The raw `CV_MAKETYPE(depth, channels)` value.
+    pub fn mat_type(&self) -> i32 {
+        unsafe { ffi::cv_mat_type(self.pointer) }
+    }
+
+    /// `None` above four channels, as for [`Mat::data_type`].
+    pub fn data_type(&self) -> Option<DataTypes> {
+        DataTypes::from_bits(self.mat_type())
+    }
+
+    /// Element depth such as `CV_16U`.
+    pub fn depth(&self) -> DataTypes {
+        DataTypes::from_bits_truncate(self.mat_type() & 7)
+    }
+
+    pub fn is<T: Depth, const C: usize>(&self) -> bool {
+        self.mat_type() == Mat::<T, C>::TYPE
+    }
+
+    /// More than 2 for Mats that only convert to [`MatNd`](super::MatNd).
+    pub fn dims(&self) -> i32 {
+        unsafe { ffi::cv_mat_dims(self.pointer) }
+    }
+
+    /// -1 when `dims() > 2`, as in OpenCV.
+    pub fn cols(&self) -> i32 {
+        unsafe { ffi::cv_mat_cols(self.pointer) }
+    }
+
+    pub fn rows(&self) -> i32 {
+        unsafe { ffi::cv_mat_rows(self.pointer) }
+    }
+
+    pub fn channels(&self) -> i32 {
+        unsafe { ffi::cv_mat_channels(self.pointer) }
+    }
+}
+
+impl Clone for DynMat {
+    /// Deep copy via `cv::Mat::clone`.
+    fn clone(&self) -> Self {
+        let pointer = Result::from(unsafe { ffi::cv_mat_clone(self.pointer) })
+            .expect("cv::Mat::clone failed");
+        Self::from_ptr(pointer)
+    }
+}
+
+// Same reasoning as for `Mat`.
+unsafe impl Send for DynMat {}
+unsafe impl Sync for DynMat {}
+
+impl Drop for DynMat {
+    fn drop(&mut self) {
+        unsafe { ffi::cv_release_mat(self.pointer) }
+    }
+}
+
+impl<T, const C: usize> From<Mat<T, C>> for DynMat {
+    fn from(mat: Mat<T, C>) -> Self {
+        let mat = ManuallyDrop::new(mat);
+        Self::from_ptr(mat.pointer)
+    }
+}
+
+impl<T: Depth, const C: usize> TryFrom<DynMat> for Mat<T, C> {
+    type Error = Error;
+
+    /// Fails with [`Error::TypeMismatch`] unless the type is exactly `T` with `C` channels,
+    /// and with [`Error::InvalidArgument`] for N-d Mats, which need [`MatNd`](super::MatNd).
+    fn try_from(mat: DynMat) -> Result<Self> {
+        if !mat.is::<T, C>() {
+            return Err(Error::TypeMismatch {
+                expected: Mat::<T, C>::TYPE,
+                actual: mat.mat_type(),
+            });
+        }
+        if mat.dims() > 2 {
+            return Err(Error::InvalidArgument(format!(
+                "cannot view a {}-d Mat as a 2-d Mat",
+                mat.dims()
+            )));
+        }
+        let mat = ManuallyDrop::new(mat);
+        Ok(Mat::from_ptr(mat.pointer))
+    }
+}
+
+/// Run `body` with `name` bound to the [`DynMat`] converted to its concrete
+/// `Mat<T, C>`, for every depth and 1 to 4 channels.
+///
+/// `body` must compile for each of those types and evaluates to `Result<R>`,
+/// failing with [`Error::InvalidArgument`](crate::Error::InvalidArgument) for
+/// Mats with more channels or more than two dimensions.
+///
+/// ```no_run
+/// use rxcv::{match_type, DynMat};
+///
+/// fn describe(mat: DynMat) -> rxcv::Result<String> {
+///     match_type!(mat, m => format!("{}x{} {}", m.cols(), m.rows(), std::any::type_name_of_val(&m)))
+/// }
+/// ```
+#[macro_export]
+macro_rules! match_type {
+    ($mat:expr, $name:ident => $body:expr) => {
+        $crate::match_type!(@arms $mat, $name => $body;
+            u8, i8, u16, i16, i32, f32, f64, $crate::core::f16)
+    };
+    (@arms $mat:expr, $name:ident => $body:expr; $($t:ty),*) => {
+        $crate::match_type!(@channels $mat, $name => $body;
+            $(($t, 1) ($t, 2) ($t, 3) ($t, 4))*)
+    };
+    (@channels $mat:expr, $name:ident => $body:expr; $(($t:ty, $c:literal))*) => {{
+        let mat: $crate::core::DynMat = $mat;
+        $(
+            if mat.is::<$t, $c>() {
+                match $crate::core::Mat::<$t, $c>::try_from(mat) {
+                    Ok($name) => Ok($body),
+                    Err(e) => Err(e),
+                }
+            } else
+        )* {
+            Err($crate::Error::InvalidArgument(format!(
+                "unsupported Mat type {}",
+                mat.mat_type()
+            )))
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{f16, MatNd};
+
+    fn describe(mat: DynMat) -> Result<(i32, usize)> {
+        match_type!(mat, m => (m.channels(), std::mem::size_of_val(&m.data()[0])))
+    }
+
+    #[test]
+    fn try_from_test() {
+        let src = Mat::<u16, 3>::from_fn(2, 3, |r, c| [r as u16, c as u16, 7]).unwrap();
+        let mat = DynMat::from(src);
+        assert_eq!(mat.data_type(), Some(DataTypes::CV_16UC3));
+        assert_eq!(mat.depth(), DataTypes::CV_16U);
+        assert_eq!((mat.rows(), mat.cols(), mat.channels()), (2, 3, 3));
+        assert!(mat.is::<u16, 3>());
+        assert!(!mat.is::<u8, 3>());
+
+        assert_eq!(
+            Mat::<u8, 3>::try_from(mat.clone()).err(),
+            Some(Error::TypeMismatch {
+                expected: DataTypes::CV_8UC3.bits(),
+                actual: DataTypes::CV_16UC3.bits(),
+            })
+        );
+        assert_eq!(
+            Mat::<u8, 3>::try_from(mat.clone()).unwrap_err().to_string(),
+            "expected Mat type CV_8UC3, got CV_16UC3"
+        );
+        let back: Mat<u16, 3> = mat.try_into().unwrap();
+        assert_eq!(back.at(1, 2), &[1, 2, 7]);
+    }
+
+    #[test]
+    fn match_type_test() {
+        let mat = DynMat::from(Mat::<f32, 1>::zeros(2, 2).unwrap());
+        assert_eq!(describe(mat).unwrap(), (1, 4));
+        let mat = DynMat::from(Mat::<f16, 4>::zeros(2, 2).unwrap());
+        assert_eq!(describe(mat).unwrap(), (4, 2));
+        let mat = DynMat::from(Mat::<u8, 5>::zeros(2, 2).unwrap());
+        assert!(matches!(describe(mat), Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn nd_test() {
+        let mat = DynMat::from(MatNd::<u8>::zeros(&[2, 3, 4]).unwrap());
+        assert!(mat.is::<u8, 1>());
+        assert!(matches!(
+            Mat::<u8, 1>::try_from(mat.clone()),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(describe(mat), Err(Error::InvalidArgument(_))));
+    }
+}
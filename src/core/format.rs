@@ -0,0 +1,229 @@
+//! Text output and comparison of Mats.
+use super::{consts::type_name, Bytes, Depth, FormatStyles, Mat, Point};
+use crate::result::{Error, Result};
+use std::fmt;
+
+mod ffi {
+    use crate::{
+        core::{BytesPointer, MatPointer, Point},
+        ffi::FFIResult,
+    };
+
+    #[link(name = "rxcv", kind = "static")]
+    extern "C" {
+        pub(super) fn cv_mat_format(
+            src: *const MatPointer,
+            fmt: i32,
+            dst: *const BytesPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_mat_max_abs_diff(
+            a: *const MatPointer,
+            b: *const MatPointer,
+            loc: *mut Point,
+        ) -> FFIResult<f64>;
+    }
+}
+
+/// Pixels printed by `Debug` before the rest is elided.
+const DEBUG_PIXELS: usize = 8;
+
+/// Largest element difference between two Mats, as found by [`Mat::max_abs_diff`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MatDiff {
+    /// Equal elements, including equal infinities, differ by 0. An element
+    /// that is NaN in either Mat differs by infinity.
+    pub max_diff: f64,
+    /// Pixel holding the largest difference, `(0, 0)` for equal Mats.
+    pub location: Point,
+}
+
+impl<T: Depth, const C: usize> Mat<T, C> {
+    /// Text in one of the `cv::format` styles.
+    pub fn format(&self, style: FormatStyles) -> Result<String> {
+        let bytes = Bytes::default();
+        Result::from(unsafe { ffi::cv_mat_format(self.pointer, style.bits(), bytes.pointer) })?;
+        Ok(String::from_utf8_lossy(bytes.data()).into_owned())
+    }
+
+    /// Adapter for `{}` with a chosen style. `Display` on the Mat itself uses `FMT_DEFAULT`.
+    pub fn display(&self, style: FormatStyles) -> MatDisplay<'_, T, C> {
+        MatDisplay { mat: self, style }
+    }
+
+    /// Largest absolute difference to `other`, i.e. their `NORM_INF` distance,
+    /// and where it occurs.
+    pub fn max_abs_diff(&self, other: &Self) -> Result<MatDiff> {
+        if (self.rows(), self.cols()) != (other.rows(), other.cols()) {
+            return Err(Error::InvalidArgument(format!(
+                "cannot compare {}x{} Mat with {}x{} Mat",
+                self.rows(),
+                self.cols(),
+                other.rows(),
+                other.cols()
+            )));
+        }
+        let mut location = Point::default();
+        let max_diff = Result::from(unsafe {
+            ffi::cv_mat_max_abs_diff(self.pointer, other.pointer, &mut location)
+        })?;
+        Ok(MatDiff { max_diff, location })
+    }
+
+    /// `None` if every element is within `tolerance` of `other`, otherwise the
+    /// worst difference. Fails if the shapes differ.
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> Result<Option<MatDiff>> {
+        let diff = self.max_abs_diff(other)?;
+        Ok((diff.max_diff > tolerance).then_some(diff))
+    }
+}
+
+/// Returned by [`Mat::display`].
+pub struct MatDisplay<'a, T, const C: usize> {
+    mat: &'a Mat<T, C>,
+    style: FormatStyles,
+}
+
+impl<T: Depth, const C: usize> fmt::Display for MatDisplay<'_, T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = self.mat.format(self.style).map_err(|_| fmt::Error)?;
+        f.write_str(&text)
+    }
+}
+
+impl<T: Depth, const C: usize> fmt::Display for Mat<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(FormatStyles::FMT_DEFAULT).fmt(f)
+    }
+}
+
+struct Preview<'a, T, const C: usize>(&'a Mat<T, C>);
+
+impl<T: fmt::Debug, const C: usize> fmt::Debug for Preview<'_, T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cols = self.0.cols().max(0) as usize;
+        let total = self.0.rows().max(0) as usize * cols;
+        let mut list = f.debug_list();
+        for i in 0..total.min(DEBUG_PIXELS) {
+            list.entry(self.0.at(i / cols, i % cols));
+        }
+        if total > DEBUG_PIXELS {
+            list.entry(&format_args!("... {} more", total - DEBUG_PIXELS));
+        }
+        list.finish()
+    }
+}
+
+/// Shape, type and the first few pixels in row-major order.
+impl<T: Depth + fmt::Debug, const C: usize> fmt::Debug for Mat<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mat")
+            .field("rows", &self.rows())
+            .field("cols", &self.cols())
+            .field("type", &format_args!("{}", type_name(Self::TYPE)))
+            .field("data", &Preview(self))
+            .finish()
+    }
+}
+
+/// Exact element-wise equality, so equal infinities match. Mats of different
+/// shapes are unequal, and as with floats, Mats containing NaN never equal anything.
+impl<T: Depth, const C: usize> PartialEq for Mat<T, C> {
+    fn eq(&self, other: &Self) -> bool {
+        matches!(self.max_abs_diff(other), Ok(diff) if diff.max_diff == 0.)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::f16;
+
+    #[test]
+    fn debug_test() {
+        let mat = Mat::<u8, 3>::from_fn(2, 3, |r, c| [r as u8, c as u8, 0]).unwrap();
+        assert_eq!(
+            format!("{:?}", mat),
+            "Mat { rows: 2, cols: 3, type: CV_8UC3, data: \
+             [[0, 0, 0], [0, 1, 0], [0, 2, 0], [1, 0, 0], [1, 1, 0], [1, 2, 0]] }"
+        );
+        let mat = Mat::<f32, 1>::zeros(3, 4).unwrap();
+        assert!(format!("{:?}", mat).ends_with("[0.0], ... 4 more] }"));
+        let mat = Mat::<i16, 7>::zeros(0, 0).unwrap();
+        assert_eq!(
+            format!("{:?}", mat),
+            "Mat { rows: 0, cols: 0, type: CV_16SC7, data: [] }"
+        );
+    }
+
+    #[test]
+    fn display_test() {
+        let mat = Mat::<u8, 1>::from_shape_vec(2, 2, &[1, 2, 3, 4]).unwrap();
+        let text = mat.to_string();
+        assert!(text.starts_with('[') && text.ends_with(']') && text.contains(';'));
+        assert_eq!(mat.format(FormatStyles::FMT_DEFAULT).unwrap(), text);
+
+        let csv = mat.format(FormatStyles::FMT_CSV).unwrap();
+        assert_eq!(csv.lines().count(), 2);
+        assert!(!csv.contains('['));
+        let numpy = mat.display(FormatStyles::FMT_NUMPY).to_string();
+        assert!(numpy.starts_with("array([[") && numpy.ends_with("dtype='uint8')"));
+        let python = mat.format(FormatStyles::FMT_PYTHON).unwrap();
+        assert!(python.starts_with("[[") && python.ends_with("]]"));
+        let c = mat.format(FormatStyles::FMT_C).unwrap();
+        assert!(c.starts_with('{') && c.ends_with('}'));
+    }
+
+    #[test]
+    fn eq_test() {
+        let a = Mat::<f32, 2>::from_shape_vec(2, 2, &[0., 1., 2., 3., 4., 5., 6., 7.]).unwrap();
+        let mut b = a.clone();
+        assert_eq!(a, b);
+        b.at_mut(1, 0)[1] = 5.25;
+        assert_ne!(a, b);
+        assert_eq!(a.approx_eq(&b, 0.5).unwrap(), None);
+        assert_eq!(
+            a.approx_eq(&b, 0.1).unwrap(),
+            Some(MatDiff {
+                max_diff: 0.25,
+                location: Point::new(0, 1),
+            })
+        );
+
+        b.at_mut(0, 1)[0] = f32::NAN;
+        let diff = a.max_abs_diff(&b).unwrap();
+        assert_eq!(diff.max_diff, f64::INFINITY);
+        assert_eq!(diff.location, Point::new(1, 0));
+        assert_ne!(b, b.clone());
+
+        assert_ne!(a, Mat::zeros(2, 3).unwrap());
+        assert!(a.approx_eq(&Mat::zeros(2, 3).unwrap(), 1.).is_err());
+    }
+
+    #[test]
+    fn eq_infinity_test() {
+        let a =
+            Mat::<f64, 1>::from_shape_vec(1, 3, &[f64::INFINITY, f64::NEG_INFINITY, 1.]).unwrap();
+        assert_eq!(a, a.clone());
+        assert_eq!(a.max_abs_diff(&a.clone()).unwrap().max_diff, 0.);
+
+        let b = Mat::<f64, 1>::from_shape_vec(1, 3, &[f64::INFINITY, f64::INFINITY, 1.]).unwrap();
+        assert_ne!(a, b);
+        assert_eq!(
+            a.approx_eq(&b, 1e9).unwrap(),
+            Some(MatDiff {
+                max_diff: f64::INFINITY,
+                location: Point::new(1, 0),
+            })
+        );
+    }
+
+    #[test]
+    fn eq_saturation_test() {
+        let a = Mat::<u8, 1>::from_shape_vec(1, 2, &[0, 255]).unwrap();
+        let b = Mat::<u8, 1>::from_shape_vec(1, 2, &[255, 255]).unwrap();
+        assert_eq!(a.max_abs_diff(&b).unwrap().max_diff, 255.);
+        let h = Mat::<f16, 1>::full(2, 2, [f16::ONE]).unwrap();
+        assert_eq!(h, h.clone());
+        assert_ne!(h, Mat::zeros(2, 2).unwrap());
+    }
+}
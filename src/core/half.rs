@@ -0,0 +1,193 @@
+use super::{DataTypes, Depth};
+use std::{cmp::Ordering, fmt};
+
+/// IEEE 754 half-precision float, the element type of `CV_16F` Mats.
+///
+/// Only a storage type: convert to `f32` for arithmetic, or convert whole
+/// Mats with [`Mat::convert_to`](super::Mat::convert_to).
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+#[derive(Clone, Copy, Default)]
+pub struct f16(u16);
+
+impl f16 {
+    pub const ZERO: f16 = f16(0x0000);
+    pub const ONE: f16 = f16(0x3c00);
+    pub const MAX: f16 = f16(0x7bff);
+    pub const MIN: f16 = f16(0xfbff);
+    pub const INFINITY: f16 = f16(0x7c00);
+    pub const NEG_INFINITY: f16 = f16(0xfc00);
+    pub const NAN: f16 = f16(0x7e00);
+
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Round to the nearest half, ties to even. Values beyond [`f16::MAX`] become infinity.
+    pub const fn from_f32(value: f32) -> Self {
+        let bits = value.to_bits();
+        let sign = ((bits >> 16) & 0x8000) as u16;
+        let exp = ((bits >> 23) & 0xff) as i32;
+        let man = bits & 0x7f_ffff;
+
+        if exp == 0xff {
+            return if man == 0 {
+                Self(sign | 0x7c00)
+            } else {
+                Self(sign | 0x7e00 | (man >> 13) as u16)
+            };
+        }
+
+        let e = exp - 127 + 15;
+        if e >= 0x1f {
+            return Self(sign | 0x7c00);
+        }
+        if e <= 0 {
+            if e < -10 {
+                return Self(sign);
+            }
+            let man = man | 0x80_0000;
+            let shift = (14 - e) as u32;
+            let round = 1 << (shift - 1);
+            let mut half = (man >> shift) as u16;
+            if man & round != 0 && man & (3 * round - 1) != 0 {
+                half += 1;
+            }
+            return Self(sign | half);
+        }
+
+        let round = 0x1000;
+        let mut half = ((e as u32) << 10 | man >> 13) as u16;
+        if man & round != 0 && man & (3 * round - 1) != 0 {
+            half += 1;
+        }
+        Self(sign | half)
+    }
+
+    /// Exact; every half is representable as `f32`.
+    pub const fn to_f32(self) -> f32 {
+        let sign = ((self.0 & 0x8000) as u32) << 16;
+        let exp = ((self.0 >> 10) & 0x1f) as i32;
+        let mut man = (self.0 & 0x3ff) as u32;
+
+        let bits = if exp == 0 {
+            if man == 0 {
+                sign
+            } else {
+                let mut e = 1;
+                while man & 0x400 == 0 {
+                    man <<= 1;
+                    e -= 1;
+                }
+                sign | ((e + 112) as u32) << 23 | (man & 0x3ff) << 13
+            }
+        } else if exp == 0x1f {
+            sign | 0x7f80_0000 | man << 13
+        } else {
+            sign | ((exp + 112) as u32) << 23 | man << 13
+        };
+        f32::from_bits(bits)
+    }
+
+    pub const fn is_nan(self) -> bool {
+        self.0 & 0x7c00 == 0x7c00 && self.0 & 0x3ff != 0
+    }
+}
+
+impl Depth for f16 {
+    const DEPTH: DataTypes = DataTypes::CV_16F;
+}
+
+impl From<f16> for f32 {
+    fn from(value: f16) -> Self {
+        value.to_f32()
+    }
+}
+
+impl From<f16> for f64 {
+    fn from(value: f16) -> Self {
+        value.to_f32() as f64
+    }
+}
+
+impl PartialEq for f16 {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_f32() == other.to_f32()
+    }
+}
+
+impl PartialOrd for f16 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
+impl fmt::Debug for f16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_f32(), f)
+    }
+}
+
+impl fmt::Display for f16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f32(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Mat;
+
+    #[test]
+    fn from_f32_test() {
+        assert_eq!(f16::from_f32(1.).to_bits(), 0x3c00);
+        assert_eq!(f16::from_f32(-2.).to_bits(), 0xc000);
+        assert_eq!(f16::from_f32(65504.).to_bits(), 0x7bff);
+        assert_eq!(f16::from_f32(65536.).to_bits(), 0x7c00);
+        assert_eq!(f16::from_f32(f32::NEG_INFINITY).to_bits(), 0xfc00);
+        assert_eq!(f16::from_f32(5.960_464_5e-8).to_bits(), 0x0001);
+        assert_eq!(f16::from_f32(1e-9).to_bits(), 0x0000);
+        // 1 + 2^-11 is halfway between 1 and the next half; ties go to even.
+        assert_eq!(f16::from_f32(1. + 1. / 2048.).to_bits(), 0x3c00);
+        assert_eq!(f16::from_f32(1. + 3. / 2048.).to_bits(), 0x3c02);
+        assert!(f16::from_f32(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn to_f32_test() {
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            if value.is_nan() {
+                assert!(value.to_f32().is_nan());
+            } else {
+                assert_eq!(f16::from_f32(value.to_f32()).to_bits(), bits);
+            }
+        }
+    }
+
+    #[test]
+    fn convert_to_test() {
+        let data = [0.5f32, -1.25, 1024., 3.];
+        let src = Mat::<f32, 1>::from_shape_vec(2, 2, &data).unwrap();
+        let half = src.convert_to::<f16>(1., 0.).unwrap();
+        assert_eq!(half.data_type(), Some(DataTypes::CV_16FC1));
+        assert_eq!(half.at(1, 0), &[f16::from_f32(1024.)]);
+        let dst = half.convert_to::<f32>(1., 0.).unwrap();
+        assert_eq!(dst.data(), &data);
+    }
+
+    #[test]
+    fn ndarray_test() {
+        let a = ndarray::Array::from_shape_fn((2, 3, 4), |(r, c, ch)| {
+            f16::from_f32((r * 12 + c * 4 + ch) as f32 / 4.)
+        });
+        let mat = Mat::<f16, 4>::from_ndarray(&a).unwrap();
+        assert_eq!(mat.data_type(), Some(DataTypes::CV_16FC4));
+        assert_eq!(mat.to_ndarray(), a);
+    }
+}
@@ -0,0 +1,295 @@
+//! Iterators over the pixels and rows of a Mat. Rows are located through the Mat
+//! step, so views from [`Mat::roi`] and [`Mat::col_range`] are walked correctly.
+use super::{Mat, MatMut};
+use std::{iter::FusedIterator, marker::PhantomData};
+
+/// Pixel positions `front..back` in row-major order.
+struct Cursor<T, const C: usize> {
+    data: *const u8,
+    step: usize,
+    cols: usize,
+    front: usize,
+    back: usize,
+    element: PhantomData<T>,
+}
+
+impl<T, const C: usize> Cursor<T, C> {
+    fn new(mat: &Mat<T, C>) -> Self {
+        let cols = mat.cols().max(0) as usize;
+        Self {
+            data: mat.data_ptr() as *const u8,
+            step: mat.step(),
+            cols,
+            front: 0,
+            back: mat.rows().max(0) as usize * cols,
+            element: PhantomData,
+        }
+    }
+
+    fn position(&self, index: usize) -> (usize, usize) {
+        (index / self.cols, index % self.cols)
+    }
+
+    fn pixel(&self, index: usize) -> *mut [T; C] {
+        let (row, col) = self.position(index);
+        unsafe {
+            let row = self.data.add(row * self.step) as *mut T;
+            row.add(col * C) as *mut [T; C]
+        }
+    }
+
+    fn next(&mut self) -> Option<usize> {
+        (self.front < self.back).then(|| {
+            self.front += 1;
+            self.front - 1
+        })
+    }
+
+    fn next_back(&mut self) -> Option<usize> {
+        (self.front < self.back).then(|| {
+            self.back -= 1;
+            self.back
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+macro_rules! impl_pixel_iter {
+    ($name:ident, $item:ty, |$cursor:ident, $index:ident| $get:expr) => {
+        impl<'a, T, const C: usize> Iterator for $name<'a, T, C> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let $cursor = &mut self.cursor;
+                let $index = $cursor.next()?;
+                Some($get)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.cursor.len(), Some(self.cursor.len()))
+            }
+        }
+
+        impl<'a, T, const C: usize> DoubleEndedIterator for $name<'a, T, C> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let $cursor = &mut self.cursor;
+                let $index = $cursor.next_back()?;
+                Some($get)
+            }
+        }
+
+        impl<T, const C: usize> ExactSizeIterator for $name<'_, T, C> {}
+        impl<T, const C: usize> FusedIterator for $name<'_, T, C> {}
+    };
+}
+
+/// Returned by [`Mat::pixels`].
+pub struct Pixels<'a, T, const C: usize> {
+    cursor: Cursor<T, C>,
+    mat: PhantomData<&'a Mat<T, C>>,
+}
+
+impl_pixel_iter!(Pixels, &'a [T; C], |cursor, index| unsafe {
+    &*cursor.pixel(index)
+});
+
+/// Returned by [`Mat::pixels_mut`].
+pub struct PixelsMut<'a, T, const C: usize> {
+    cursor: Cursor<T, C>,
+    mat: PhantomData<&'a mut Mat<T, C>>,
+}
+
+impl_pixel_iter!(PixelsMut, &'a mut [T; C], |cursor, index| unsafe {
+    &mut *cursor.pixel(index)
+});
+
+/// Returned by [`Mat::indexed_pixels`].
+pub struct IndexedPixels<'a, T, const C: usize> {
+    cursor: Cursor<T, C>,
+    mat: PhantomData<&'a Mat<T, C>>,
+}
+
+impl_pixel_iter!(
+    IndexedPixels,
+    (usize, usize, &'a [T; C]),
+    |cursor, index| {
+        let (row, col) = cursor.position(index);
+        (row, col, unsafe { &*cursor.pixel(index) })
+    }
+);
+
+// Send and Sync as for the slice iterators these stand in for.
+unsafe impl<T: Sync, const C: usize> Send for Pixels<'_, T, C> {}
+unsafe impl<T: Sync, const C: usize> Sync for Pixels<'_, T, C> {}
+unsafe impl<T: Send, const C: usize> Send for PixelsMut<'_, T, C> {}
+unsafe impl<T: Sync, const C: usize> Sync for PixelsMut<'_, T, C> {}
+unsafe impl<T: Sync, const C: usize> Send for IndexedPixels<'_, T, C> {}
+unsafe impl<T: Sync, const C: usize> Sync for IndexedPixels<'_, T, C> {}
+
+/// Returned by [`Mat::rows_iter`].
+pub struct Rows<'a, T, const C: usize> {
+    mat: &'a Mat<T, C>,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T, const C: usize> Iterator for Rows<'a, T, C> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.front += 1;
+            Some(self.mat.row(self.front - 1))
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T, const C: usize> DoubleEndedIterator for Rows<'_, T, C> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.mat.row(self.back))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, const C: usize> ExactSizeIterator for Rows<'_, T, C> {}
+impl<T, const C: usize> FusedIterator for Rows<'_, T, C> {}
+
+impl<T, const C: usize> Mat<T, C> {
+    /// Pixels in row-major order.
+    pub fn pixels(&self) -> Pixels<'_, T, C> {
+        Pixels {
+            cursor: Cursor::new(self),
+            mat: PhantomData,
+        }
+    }
+
+    pub fn pixels_mut(&mut self) -> PixelsMut<'_, T, C> {
+        PixelsMut {
+            cursor: Cursor::new(self),
+            mat: PhantomData,
+        }
+    }
+
+    /// Pixels in row-major order with their `(row, col)`.
+    pub fn indexed_pixels(&self) -> IndexedPixels<'_, T, C> {
+        IndexedPixels {
+            cursor: Cursor::new(self),
+            mat: PhantomData,
+        }
+    }
+
+    /// Rows as slices of `cols * C` elements, as returned by [`Mat::row`].
+    pub fn rows_iter(&self) -> Rows<'_, T, C> {
+        Rows {
+            mat: self,
+            front: 0,
+            back: self.rows().max(0) as usize,
+        }
+    }
+}
+
+impl<'a, T, const C: usize> IntoIterator for &'a Mat<T, C> {
+    type Item = &'a [T; C];
+    type IntoIter = Pixels<'a, T, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.pixels()
+    }
+}
+
+impl<T, const C: usize> MatMut<'_, T, C> {
+    pub fn pixels_mut(&mut self) -> PixelsMut<'_, T, C> {
+        PixelsMut {
+            cursor: Cursor::new(self),
+            mat: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Rect;
+
+    fn mat() -> Mat<u8, 2> {
+        Mat::from_fn(3, 4, |r, c| [r as u8, c as u8]).unwrap()
+    }
+
+    #[test]
+    fn pixels_test() {
+        let mat = mat();
+        let pixels = mat.pixels();
+        assert_eq!(pixels.len(), 12);
+        assert_eq!(pixels.flatten().copied().collect::<Vec<_>>(), mat.data());
+        assert_eq!(mat.pixels().next_back(), Some(&[2, 3]));
+        assert_eq!(mat.pixels().rev().nth(4), Some(&[1, 3]));
+
+        let mut pixels = mat.pixels();
+        pixels.next();
+        pixels.next_back();
+        assert_eq!(pixels.len(), 10);
+        assert_eq!((&mat).into_iter().count(), 12);
+        assert_eq!(Mat::<u8, 2>::new().unwrap().pixels().next(), None);
+    }
+
+    #[test]
+    fn indexed_pixels_test() {
+        let mat = mat();
+        assert!(mat
+            .indexed_pixels()
+            .all(|(r, c, px)| px == &[r as u8, c as u8]));
+        assert_eq!(mat.indexed_pixels().nth(5), Some((1, 1, &[1, 1])));
+        assert_eq!(mat.indexed_pixels().next_back(), Some((2, 3, &[2, 3])));
+    }
+
+    #[test]
+    fn rows_iter_test() {
+        let mat = mat();
+        let mut rows = mat.rows_iter();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows.next_back(), Some(&[2, 0, 2, 1, 2, 2, 2, 3][..]));
+        assert_eq!(rows.next(), Some(&[0, 0, 0, 1, 0, 2, 0, 3][..]));
+        assert_eq!(rows.len(), 1);
+        assert_eq!(mat.rows_iter().flatten().count(), 24);
+    }
+
+    #[test]
+    fn roi_test() {
+        let mut mat = mat();
+        {
+            let roi = mat.roi(Rect::new(1, 1, 2, 2)).unwrap();
+            let pixels = roi.pixels().collect::<Vec<_>>();
+            assert_eq!(pixels, [&[1, 1], &[1, 2], &[2, 1], &[2, 2]]);
+            let rows = roi.rows_iter().collect::<Vec<_>>();
+            assert_eq!(rows, [&[1, 1, 1, 2][..], &[2, 1, 2, 2][..]]);
+        }
+        {
+            let mut roi = mat.roi_mut(Rect::new(2, 0, 2, 3)).unwrap();
+            for px in roi.pixels_mut() {
+                px[0] = 9;
+            }
+        }
+        for px in mat.pixels_mut().rev().take(4) {
+            px[1] += 10;
+        }
+        assert_eq!(mat.at(0, 1), &[0, 1]);
+        assert_eq!(mat.at(0, 2), &[9, 2]);
+        assert_eq!(mat.at(1, 3), &[9, 3]);
+        assert_eq!(mat.at(2, 0), &[2, 10]);
+        assert_eq!(mat.at(2, 3), &[9, 13]);
+    }
+}
@@ -0,0 +1,205 @@
+use super::{BorderTypes, Depth, FlipCode, Mat, RotateFlags, Scalar};
+use crate::result::Result;
+
+mod ffi {
+    use crate::{
+        core::{MatPointer, Scalar},
+        ffi::FFIResult,
+    };
+
+    #[link(name = "rxcv", kind = "static")]
+    extern "C" {
+        pub(super) fn cv_transpose(
+            src: *const MatPointer,
+            dst: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_flip(
+            src: *const MatPointer,
+            dst: *const MatPointer,
+            flip_code: i32,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_rotate(
+            src: *const MatPointer,
+            dst: *const MatPointer,
+            rotate_code: i32,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_repeat(
+            src: *const MatPointer,
+            ny: i32,
+            nx: i32,
+            dst: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_hconcat(
+            src: *const *const MatPointer,
+            count: usize,
+            dst: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_vconcat(
+            src: *const *const MatPointer,
+            count: usize,
+            dst: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_copy_make_border(
+            src: *const MatPointer,
+            dst: *const MatPointer,
+            top: i32,
+            bottom: i32,
+            left: i32,
+            right: i32,
+            border_type: i32,
+            value: Scalar,
+        ) -> FFIResult<i32>;
+    }
+}
+
+impl<T: Depth, const C: usize> Mat<T, C> {
+    pub fn transpose(&self) -> Result<Self> {
+        let dst = Mat::new()?;
+        Result::from(unsafe { ffi::cv_transpose(self.pointer, dst.pointer) })?;
+        Ok(dst)
+    }
+
+    /// Same as [`transpose`](Self::transpose), after `cv::Mat::t`.
+    pub fn t(&self) -> Result<Self> {
+        self.transpose()
+    }
+
+    pub fn flip(&self, flip_code: FlipCode) -> Result<Self> {
+        let dst = Mat::new()?;
+        Result::from(unsafe { ffi::cv_flip(self.pointer, dst.pointer, flip_code.bits()) })?;
+        Ok(dst)
+    }
+
+    /// Rotate by a multiple of 90 degrees without interpolation.
+    pub fn rotate(&self, rotate_code: RotateFlags) -> Result<Self> {
+        let dst = Mat::new()?;
+        Result::from(unsafe { ffi::cv_rotate(self.pointer, dst.pointer, rotate_code.bits()) })?;
+        Ok(dst)
+    }
+
+    /// Tile `self` `ny` times vertically and `nx` times horizontally.
+    pub fn repeat(&self, ny: usize, nx: usize) -> Result<Self> {
+        let dst = Mat::new()?;
+        Result::from(unsafe { ffi::cv_repeat(self.pointer, ny as i32, nx as i32, dst.pointer) })?;
+        Ok(dst)
+    }
+
+    /// Place Mats of equal height side by side.
+    pub fn hconcat(mats: &[&Self]) -> Result<Self> {
+        let dst = Mat::new()?;
+        let pointers = mats.iter().map(|m| m.pointer).collect::<Vec<_>>();
+        Result::from(unsafe { ffi::cv_hconcat(pointers.as_ptr(), pointers.len(), dst.pointer) })?;
+        Ok(dst)
+    }
+
+    /// Stack Mats of equal width top to bottom.
+    pub fn vconcat(mats: &[&Self]) -> Result<Self> {
+        let dst = Mat::new()?;
+        let pointers = mats.iter().map(|m| m.pointer).collect::<Vec<_>>();
+        Result::from(unsafe { ffi::cv_vconcat(pointers.as_ptr(), pointers.len(), dst.pointer) })?;
+        Ok(dst)
+    }
+
+    /// Pad with a border extrapolated as in the filters. `value` is used by `BORDER_CONSTANT`.
+    pub fn copy_make_border(
+        &self,
+        top: usize,
+        bottom: usize,
+        left: usize,
+        right: usize,
+        border_type: BorderTypes,
+        value: Scalar,
+    ) -> Result<Self> {
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_copy_make_border(
+                self.pointer,
+                dst.pointer,
+                top as i32,
+                bottom as i32,
+                left as i32,
+                right as i32,
+                border_type.bits(),
+                value,
+            )
+        })?;
+        Ok(dst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 1 2 3
+    // 4 5 6
+    fn mat() -> Mat<u8, 1> {
+        Mat::from_shape_vec(2, 3, &[1, 2, 3, 4, 5, 6]).unwrap()
+    }
+
+    #[test]
+    fn transpose_test() {
+        let dst = mat().transpose().unwrap();
+        assert_eq!(dst.rows(), 3);
+        assert_eq!(dst.data(), &[1, 4, 2, 5, 3, 6]);
+        assert_eq!(mat().t().unwrap().data(), dst.data());
+    }
+
+    #[test]
+    fn flip_test() {
+        let src = mat();
+        let dst = src.flip(FlipCode::FLIP_VERTICAL).unwrap();
+        assert_eq!(dst.data(), &[4, 5, 6, 1, 2, 3]);
+        let dst = src.flip(FlipCode::FLIP_HORIZONTAL).unwrap();
+        assert_eq!(dst.data(), &[3, 2, 1, 6, 5, 4]);
+        let dst = src.flip(FlipCode::FLIP_BOTH).unwrap();
+        assert_eq!(dst.data(), &[6, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn rotate_test() {
+        let src = mat();
+        let dst = src.rotate(RotateFlags::ROTATE_90_CLOCKWISE).unwrap();
+        assert_eq!((dst.rows(), dst.cols()), (3, 2));
+        assert_eq!(dst.data(), &[4, 1, 5, 2, 6, 3]);
+        let dst = src.rotate(RotateFlags::ROTATE_180).unwrap();
+        assert_eq!(dst.data(), &[6, 5, 4, 3, 2, 1]);
+        let dst = src.rotate(RotateFlags::ROTATE_90_COUNTERCLOCKWISE).unwrap();
+        assert_eq!(dst.data(), &[3, 6, 2, 5, 1, 4]);
+    }
+
+    #[test]
+    fn repeat_test() {
+        let dst = mat().repeat(2, 3).unwrap();
+        assert_eq!((dst.rows(), dst.cols()), (4, 9));
+        assert_eq!(dst.at(3, 7), &[5]);
+    }
+
+    #[test]
+    fn concat_test() {
+        let a = mat();
+        let b = mat().flip(FlipCode::FLIP_BOTH).unwrap();
+        let h = Mat::hconcat(&[&a, &b]).unwrap();
+        assert_eq!(h.row(1), &[4, 5, 6, 3, 2, 1]);
+        let v = Mat::vconcat(&[&a, &b, &a]).unwrap();
+        assert_eq!((v.rows(), v.cols()), (6, 3));
+        assert_eq!(v.row(2), &[6, 5, 4]);
+        let c = a.transpose().unwrap();
+        assert!(Mat::hconcat(&[&a, &c]).is_err());
+    }
+
+    #[test]
+    fn copy_make_border_test() {
+        let src = mat();
+        let dst = src
+            .copy_make_border(1, 0, 2, 1, BorderTypes::BORDER_CONSTANT, Scalar::all(9.))
+            .unwrap();
+        assert_eq!((dst.rows(), dst.cols()), (3, 6));
+        assert_eq!(dst.row(0), &[9; 6]);
+        assert_eq!(dst.row(1), &[9, 9, 1, 2, 3, 9]);
+        let dst = src
+            .copy_make_border(0, 0, 1, 1, BorderTypes::BORDER_REPLICATE, Scalar::default())
+            .unwrap();
+        assert_eq!(dst.row(1), &[4, 4, 5, 6, 6]);
+    }
+}
@@ -0,0 +1,357 @@
+//! Dense linear algebra on single-channel `f32` and `f64` Mats.
+use super::{DecompTypes, Depth, GemmFlags, Mat, MatPointer, SVDFlags};
+use crate::result::{Error, Result};
+
+mod ffi {
+    use crate::{core::MatPointer, ffi::FFIResult};
+
+    #[link(name = "rxcv", kind = "static")]
+    extern "C" {
+        pub(super) fn cv_gemm(
+            src1: *const MatPointer,
+            src2: *const MatPointer,
+            alpha: f64,
+            src3: *const MatPointer,
+            beta: f64,
+            dst: *const MatPointer,
+            flags: i32,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_invert(
+            src: *const MatPointer,
+            dst: *const MatPointer,
+            flags: i32,
+        ) -> FFIResult<f64>;
+        pub(super) fn cv_solve(
+            src1: *const MatPointer,
+            src2: *const MatPointer,
+            dst: *const MatPointer,
+            flags: i32,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_determinant(src: *const MatPointer) -> FFIResult<f64>;
+        pub(super) fn cv_trace(src: *const MatPointer) -> FFIResult<f64>;
+        pub(super) fn cv_svd_compute(
+            src: *const MatPointer,
+            w: *const MatPointer,
+            u: *const MatPointer,
+            vt: *const MatPointer,
+            flags: i32,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_eigen(
+            src: *const MatPointer,
+            values: *const MatPointer,
+            vectors: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_eigen_non_symmetric(
+            src: *const MatPointer,
+            values: *const MatPointer,
+            vectors: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_solve_poly(
+            coeffs: *const MatPointer,
+            roots: *const MatPointer,
+            max_iters: i32,
+        ) -> FFIResult<f64>;
+        pub(super) fn cv_solve_cubic(
+            coeffs: *const MatPointer,
+            roots: *const MatPointer,
+        ) -> FFIResult<i32>;
+    }
+}
+
+impl<T: Depth, const C: usize> Mat<T, C> {
+    fn check_square(&self) -> Result<()> {
+        let (rows, cols) = (self.rows(), self.cols());
+        if rows == cols {
+            Ok(())
+        } else {
+            Err(Error::NotSquare { rows, cols })
+        }
+    }
+}
+
+/// SVD and QR find a least-squares solution, and `DECOMP_NORMAL` solves the
+/// normal equations, so only the remaining methods need a square matrix.
+fn needs_square(flags: DecompTypes) -> bool {
+    let method = flags - DecompTypes::DECOMP_NORMAL;
+    !(flags.contains(DecompTypes::DECOMP_NORMAL)
+        || method == DecompTypes::DECOMP_SVD
+        || method == DecompTypes::DECOMP_QR)
+}
+
+fn optional_pointer<T, const C: usize>(mat: Option<&Mat<T, C>>) -> *const MatPointer {
+    mat.map_or(std::ptr::null(), |mat| mat.pointer)
+}
+
+macro_rules! impl_linalg {
+    ($t:ty) => {
+        impl Mat<$t, 1> {
+            /// `alpha * op(self) * op(src2) + beta * op(src3)`, where `flags` selects
+            /// which operands are transposed.
+            pub fn gemm(
+                &self,
+                src2: &Self,
+                alpha: f64,
+                src3: Option<&Self>,
+                beta: f64,
+                flags: GemmFlags,
+            ) -> Result<Self> {
+                let dst = Mat::new()?;
+                Result::from(unsafe {
+                    ffi::cv_gemm(
+                        self.pointer,
+                        src2.pointer,
+                        alpha,
+                        optional_pointer(src3),
+                        beta,
+                        dst.pointer,
+                        flags.bits(),
+                    )
+                })?;
+                Ok(dst)
+            }
+
+            /// Matrix product `self * other`.
+            pub fn matmul(&self, other: &Self) -> Result<Self> {
+                self.gemm(other, 1., None, 0., GemmFlags::empty())
+            }
+
+            /// `(result, inverse)`. The result is 0 for a singular matrix with
+            /// `DECOMP_LU` or `DECOMP_CHOLESKY`, and the inverse condition number
+            /// with `DECOMP_SVD`, which also accepts non-square input (pseudo-inverse).
+            pub fn invert(&self, flags: DecompTypes) -> Result<(f64, Self)> {
+                if needs_square(flags) {
+                    self.check_square()?;
+                }
+                let dst = Mat::new()?;
+                let result = Result::from(unsafe {
+                    ffi::cv_invert(self.pointer, dst.pointer, flags.bits())
+                })?;
+                Ok((result, dst))
+            }
+
+            /// Solve `self * x = rhs`. `None` when the matrix is singular.
+            pub fn solve(&self, rhs: &Self, flags: DecompTypes) -> Result<Option<Self>> {
+                if needs_square(flags) {
+                    self.check_square()?;
+                }
+                let dst = Mat::new()?;
+                let solved = Result::from(unsafe {
+                    ffi::cv_solve(self.pointer, rhs.pointer, dst.pointer, flags.bits())
+                })?;
+                Ok((solved != 0).then_some(dst))
+            }
+
+            pub fn determinant(&self) -> Result<f64> {
+                self.check_square()?;
+                Result::from(unsafe { ffi::cv_determinant(self.pointer) })
+            }
+
+            pub fn trace(&self) -> Result<f64> {
+                self.check_square()?;
+                Result::from(unsafe { ffi::cv_trace(self.pointer) })
+            }
+
+            /// `(w, u, vt)` such that `self = u * diag(w) * vt`, singular values in
+            /// descending order. `u` and `vt` are empty with `NO_UV`.
+            pub fn svd(&self, flags: SVDFlags) -> Result<(Self, Self, Self)> {
+                let w = Mat::new()?;
+                let u = Mat::new()?;
+                let vt = Mat::new()?;
+                Result::from(unsafe {
+                    ffi::cv_svd_compute(
+                        self.pointer,
+                        w.pointer,
+                        u.pointer,
+                        vt.pointer,
+                        flags.bits(),
+                    )
+                })?;
+                Ok((w, u, vt))
+            }
+
+            /// `(values, vectors)` of a symmetric matrix. Eigenvalues are a column in
+            /// descending order and the eigenvectors are the matching rows.
+            pub fn eigen(&self) -> Result<(Self, Self)> {
+                self.check_square()?;
+                let values = Mat::new()?;
+                let vectors = Mat::new()?;
+                Result::from(unsafe {
+                    ffi::cv_eigen(self.pointer, values.pointer, vectors.pointer)
+                })?;
+                Ok((values, vectors))
+            }
+
+            /// As [`eigen`](Self::eigen) for a general matrix. Only real
+            /// eigenvalues are supported.
+            pub fn eigen_non_symmetric(&self) -> Result<(Self, Self)> {
+                self.check_square()?;
+                let values = Mat::new()?;
+                let vectors = Mat::new()?;
+                Result::from(unsafe {
+                    ffi::cv_eigen_non_symmetric(self.pointer, values.pointer, vectors.pointer)
+                })?;
+                Ok((values, vectors))
+            }
+
+            /// Complex roots of `self[0] + self[1] * x + ... + self[n] * x^n`, one
+            /// `(re, im)` pair per row.
+            pub fn solve_poly(&self, max_iters: usize) -> Result<Mat<$t, 2>> {
+                let roots = Mat::new()?;
+                Result::from(unsafe {
+                    ffi::cv_solve_poly(self.pointer, roots.pointer, max_iters as i32)
+                })?;
+                Ok(roots)
+            }
+
+            /// Real roots of `self[0] * x^3 + self[1] * x^2 + self[2] * x + self[3]`,
+            /// or of `x^3 + self[0] * x^2 + self[1] * x + self[2]` given three
+            /// coefficients.
+            pub fn solve_cubic(&self) -> Result<Vec<$t>> {
+                let roots = Mat::<$t, 1>::new()?;
+                let count =
+                    Result::from(unsafe { ffi::cv_solve_cubic(self.pointer, roots.pointer) })?;
+                if count < 0 {
+                    return Err(Error::InvalidArgument(
+                        "every value is a root of a zero polynomial".to_string(),
+                    ));
+                }
+                Ok(roots.data()[..count as usize].to_vec())
+            }
+        }
+    };
+}
+
+impl_linalg!(f32);
+impl_linalg!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mat(rows: usize, data: &[f64]) -> Mat<f64, 1> {
+        Mat::from_shape_vec(rows, data.len() / rows, data).unwrap()
+    }
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).abs() < 1e-9, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn matmul_test() {
+        let a = mat(2, &[1., 2., 3., 4., 5., 6.]);
+        let b = mat(3, &[1., 0., 0., 1., 1., 1.]);
+        let c = a.matmul(&b).unwrap();
+        assert_eq!((c.rows(), c.cols()), (2, 2));
+        assert_eq!(c.data(), &[4., 5., 10., 11.]);
+
+        let ones = Mat::<f64, 1>::from_scalar(3, 3, crate::core::Scalar::all(1.)).unwrap();
+        let d = a
+            .gemm(&a, 2., Some(&ones), 1., GemmFlags::GEMM_1_T)
+            .unwrap();
+        assert_eq!(d.row(0), &[35., 45., 55.]);
+        assert!(a.matmul(&a).is_err());
+    }
+
+    #[test]
+    fn invert_test() {
+        let a = mat(2, &[4., 7., 2., 6.]);
+        let (det, inv) = a.invert(DecompTypes::DECOMP_LU).unwrap();
+        assert!(det != 0.);
+        assert_close(inv.data(), &[0.6, -0.7, -0.2, 0.4]);
+        let (det, _) = mat(2, &[1., 2., 2., 4.])
+            .invert(DecompTypes::DECOMP_LU)
+            .unwrap();
+        assert_eq!(det, 0.);
+
+        let wide = mat(1, &[1., 2.]);
+        assert!(matches!(
+            wide.invert(DecompTypes::DECOMP_LU),
+            Err(Error::NotSquare { rows: 1, cols: 2 })
+        ));
+        let (_, pinv) = wide.invert(DecompTypes::DECOMP_SVD).unwrap();
+        assert_close(pinv.data(), &[0.2, 0.4]);
+    }
+
+    #[test]
+    fn solve_test() {
+        let a = mat(2, &[2., 1., 1., 3.]);
+        let b = mat(2, &[3., 5.]);
+        let x = a.solve(&b, DecompTypes::DECOMP_LU).unwrap().unwrap();
+        assert_close(x.data(), &[0.8, 1.4]);
+        let singular = mat(2, &[1., 2., 2., 4.]);
+        assert!(singular
+            .solve(&b, DecompTypes::DECOMP_LU)
+            .unwrap()
+            .is_none());
+
+        let tall = mat(3, &[1., 0., 0., 1., 1., 1.]);
+        let rhs = mat(3, &[1., 2., 3.]);
+        assert!(tall.solve(&rhs, DecompTypes::DECOMP_LU).is_err());
+        let x = tall.solve(&rhs, DecompTypes::DECOMP_QR).unwrap().unwrap();
+        assert_close(x.data(), &[1., 2.]);
+    }
+
+    #[test]
+    fn determinant_trace_test() {
+        let a = mat(3, &[2., 0., 1., 1., 3., 2., 1., 1., 2.]);
+        assert!((a.determinant().unwrap() - 6.).abs() < 1e-9);
+        assert_eq!(a.trace().unwrap(), 7.);
+        let wide = mat(2, &[1., 2., 3., 4., 5., 6.]);
+        assert!(matches!(wide.determinant(), Err(Error::NotSquare { .. })));
+        assert!(matches!(wide.trace(), Err(Error::NotSquare { .. })));
+    }
+
+    #[test]
+    fn svd_test() {
+        let a = mat(2, &[3., 0., 0., -2.]);
+        let (w, u, vt) = a.svd(SVDFlags::empty()).unwrap();
+        assert_close(w.data(), &[3., 2.]);
+        let s = Mat::<f64, 1>::from_shape_vec(2, 2, &[3., 0., 0., 2.]).unwrap();
+        let back = u.matmul(&s).unwrap().matmul(&vt).unwrap();
+        assert_close(back.data(), a.data());
+        let (w, u, _) = a.svd(SVDFlags::NO_UV).unwrap();
+        assert_eq!(w.rows(), 2);
+        assert_eq!(u.rows(), 0);
+    }
+
+    #[test]
+    fn eigen_test() {
+        let a = mat(2, &[2., 1., 1., 2.]);
+        let (values, vectors) = a.eigen().unwrap();
+        assert_close(values.data(), &[3., 1.]);
+        let v = vectors.row(0);
+        assert!((v[0] - v[1]).abs() < 1e-9);
+
+        let b = mat(2, &[2., 0., 1., 3.]);
+        let (values, _) = b.eigen_non_symmetric().unwrap();
+        assert_close(values.data(), &[3., 2.]);
+        assert!(matches!(
+            mat(1, &[1., 2.]).eigen(),
+            Err(Error::NotSquare { .. })
+        ));
+    }
+
+    #[test]
+    fn solve_poly_test() {
+        // x^2 + 1
+        let roots = mat(1, &[1., 0., 1.]).solve_poly(300).unwrap();
+        assert_eq!(roots.rows(), 2);
+        let mut im = roots.data().chunks(2).map(|r| r[1]).collect::<Vec<_>>();
+        im.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_close(&im, &[-1., 1.]);
+
+        // (x - 1)(x - 2)(x - 3)
+        let mut roots = mat(1, &[1., -6., 11., -6.]).solve_cubic().unwrap();
+        roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_close(&roots, &[1., 2., 3.]);
+        let roots = Mat::<f32, 1>::from_shape_vec(1, 3, &[0., 0., -8.])
+            .unwrap()
+            .solve_cubic()
+            .unwrap();
+        assert_eq!(roots.len(), 1);
+        assert!((roots[0] - 2.).abs() < 1e-5);
+    }
+}
@@ -1,12 +1,12 @@
-use std::marker::PhantomData;
+use std::{marker::PhantomData, ops::Deref, ptr::NonNull};
 
-use crate::result::Result;
+use crate::result::{Error, Result};
 
-use super::consts::*;
+use super::{consts::*, Scalar};
 
 mod ffi {
     use super::MatPointer;
-    use crate::ffi::FFIResult;
+    use crate::{core::Scalar, ffi::FFIResult};
 
     #[link(name = "rxcv", kind = "static")]
     extern "C" {
@@ -16,6 +16,19 @@ mod ffi {
             cols: i32,
             r#type: i32,
         ) -> FFIResult<*const MatPointer>;
+        pub(super) fn cv_mat_zeros(
+            rows: i32,
+            cols: i32,
+            r#type: i32,
+        ) -> FFIResult<*const MatPointer>;
+        pub(super) fn cv_mat_eye(rows: i32, cols: i32, r#type: i32)
+            -> FFIResult<*const MatPointer>;
+        pub(super) fn cv_mat_from_scalar(
+            rows: i32,
+            cols: i32,
+            r#type: i32,
+            s: Scalar,
+        ) -> FFIResult<*const MatPointer>;
         pub(super) fn cv_mat_from_shape(
             rows: i32,
             cols: i32,
@@ -27,9 +40,14 @@ mod ffi {
             r#type: i32,
             src: *const std::ffi::c_void,
         ) -> FFIResult<*const MatPointer>;
+        pub(super) fn cv_mat_clone(pointer: *const MatPointer) -> FFIResult<*const MatPointer>;
+        pub(super) fn cv_mat_share(pointer: *const MatPointer) -> FFIResult<*const MatPointer>;
+        pub(super) fn cv_mat_is_continuous(pointer: *const MatPointer) -> bool;
         pub(super) fn cv_mat_type(pointer: *const MatPointer) -> i32;
         pub(super) fn cv_mat_data(pointer: *const MatPointer) -> *const std::ffi::c_void;
+        pub(super) fn cv_mat_step(pointer: *const MatPointer) -> usize;
         pub(super) fn cv_mat_size(pointer: *const MatPointer) -> i32;
+        pub(super) fn cv_mat_dims(pointer: *const MatPointer) -> i32;
         pub(super) fn cv_mat_cols(pointer: *const MatPointer) -> i32;
         pub(super) fn cv_mat_rows(pointer: *const MatPointer) -> i32;
         pub(super) fn cv_mat_channels(pointer: *const MatPointer) -> i32;
@@ -39,63 +57,132 @@ mod ffi {
 
 pub(crate) enum MatPointer {}
 
+pub(crate) fn check_shape_len<const C: usize>(rows: usize, cols: usize, len: usize) -> Result<()> {
+    if rows * cols * C == len {
+        Ok(())
+    } else {
+        Err(Error::InvalidArgument(format!(
+            "{} elements do not fill a {}x{} Mat with {} channels",
+            len, rows, cols, C
+        )))
+    }
+}
+
+/// Element types with a matching OpenCV depth.
+pub trait Depth: Copy {
+    const DEPTH: DataTypes;
+}
+
+macro_rules! impl_depth {
+    ($t:ty, $depth:expr) => {
+        impl Depth for $t {
+            const DEPTH: DataTypes = $depth;
+        }
+    };
+}
+
+impl_depth!(u8, DataTypes::CV_8U);
+impl_depth!(i8, DataTypes::CV_8S);
+impl_depth!(u16, DataTypes::CV_16U);
+impl_depth!(i16, DataTypes::CV_16S);
+impl_depth!(i32, DataTypes::CV_32S);
+impl_depth!(f32, DataTypes::CV_32F);
+impl_depth!(f64, DataTypes::CV_64F);
+
+/// Matrix of `T` with `C` interleaved channels, backed by a `cv::Mat`.
+///
+/// OpenCV names types up to four channels (`CV_8UC4`, ...) but allows up to
+/// [`CV_CN_MAX`] channels per element. `Mat<T, C>` follows the same rule: any
+/// `C` in `1..=CV_CN_MAX` can be constructed, while `C == 0` or `C > CV_CN_MAX`
+/// fails to compile. [`Mat::data_type`] returns `None` above four channels since
+/// [`DataTypes`] has no name for those types. Most imgproc traits are only
+/// implemented up to four channels, matching what the OpenCV functions accept.
 pub struct Mat<T, const C: usize> {
     pub(crate) pointer: *const MatPointer,
     data_type: PhantomData<T>,
 }
 
-macro_rules! impl_mat {
-    ($t:ty, $c:tt, $code:expr) => {
-        impl Mat<$t, $c> {
-            pub fn from_shape(rows: usize, cols: usize) -> Result<Self> {
-                let pointer = Result::<*const MatPointer>::from(unsafe {
-                    ffi::cv_mat_from_shape(rows as i32, cols as i32, make_type($code, $c as i32))
-                })?;
-                Ok(Mat::<$t, $c>::from_ptr(pointer))
-            }
-            pub fn from_shape_vec(rows: usize, cols: usize, data: &[$t]) -> Result<Self> {
-                let pointer = Result::<*const MatPointer>::from(unsafe {
-                    ffi::cv_mat_from_shape_vec(
-                        rows as i32,
-                        cols as i32,
-                        make_type($code, $c as i32),
-                        data.as_ptr() as *const std::ffi::c_void,
-                    )
-                })?;
-                Ok(Mat::<$t, $c>::from_ptr(pointer))
-            }
-            pub fn ones(rows: i32, cols: i32) -> Result<Self> {
-                let pointer = Result::<*const MatPointer>::from(unsafe {
-                    ffi::cv_mat_ones(rows as i32, cols as i32, make_type($code, $c as i32))
-                })?;
-                Ok(Mat::<$t, $c>::from_ptr(pointer))
+impl<T: Depth, const C: usize> Mat<T, C> {
+    /// `CV_MAKETYPE(T::DEPTH, C)`, checked at compile time.
+    pub(crate) const TYPE: i32 = {
+        assert!(
+            C >= 1 && C <= CV_CN_MAX,
+            "Mat channel count must be in 1..=CV_CN_MAX"
+        );
+        make_type(T::DEPTH, C as i32)
+    };
+
+    /// Allocate without initializing the elements.
+    pub fn from_shape(rows: usize, cols: usize) -> Result<Self> {
+        let pointer =
+            Result::from(unsafe { ffi::cv_mat_from_shape(rows as i32, cols as i32, Self::TYPE) })?;
+        Ok(Self::from_ptr(pointer))
+    }
+
+    /// Copy `data`, laid out row by row with `C` interleaved channels, into a new Mat.
+    pub fn from_shape_vec(rows: usize, cols: usize, data: &[T]) -> Result<Self> {
+        check_shape_len::<C>(rows, cols, data.len())?;
+        let pointer = Result::from(unsafe {
+            ffi::cv_mat_from_shape_vec(
+                rows as i32,
+                cols as i32,
+                Self::TYPE,
+                data.as_ptr() as *const std::ffi::c_void,
+            )
+        })?;
+        Ok(Self::from_ptr(pointer))
+    }
+
+    pub fn zeros(rows: usize, cols: usize) -> Result<Self> {
+        let pointer =
+            Result::from(unsafe { ffi::cv_mat_zeros(rows as i32, cols as i32, Self::TYPE) })?;
+        Ok(Self::from_ptr(pointer))
+    }
+
+    /// `cv::Mat::ones`: as in OpenCV, only the first channel is set to one.
+    /// Use [`Mat::full`] to set every channel.
+    pub fn ones(rows: usize, cols: usize) -> Result<Self> {
+        let pointer =
+            Result::from(unsafe { ffi::cv_mat_ones(rows as i32, cols as i32, Self::TYPE) })?;
+        Ok(Self::from_ptr(pointer))
+    }
+
+    /// Ones on the diagonal of the first channel, zeros elsewhere.
+    pub fn eye(rows: usize, cols: usize) -> Result<Self> {
+        let pointer =
+            Result::from(unsafe { ffi::cv_mat_eye(rows as i32, cols as i32, Self::TYPE) })?;
+        Ok(Self::from_ptr(pointer))
+    }
+
+    /// Every pixel set to `value`.
+    pub fn full(rows: usize, cols: usize, value: [T; C]) -> Result<Self> {
+        Self::from_fn(rows, cols, |_, _| value)
+    }
+
+    /// Every pixel set to `value`, saturated to `T`. OpenCV rejects this above four channels.
+    pub fn from_scalar(rows: usize, cols: usize, value: Scalar) -> Result<Self> {
+        let pointer = Result::from(unsafe {
+            ffi::cv_mat_from_scalar(rows as i32, cols as i32, Self::TYPE, value)
+        })?;
+        Ok(Self::from_ptr(pointer))
+    }
+
+    /// Pixel `(row, col)` set to `f(row, col)`, filled row by row.
+    pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Result<Self>
+    where
+        F: FnMut(usize, usize) -> [T; C],
+    {
+        // Start zeroed: slices over uninitialized elements are undefined behaviour.
+        let mut mat = Self::zeros(rows, cols)?;
+        for row in 0..rows {
+            for (col, pixel) in mat.row_mut(row).chunks_exact_mut(C).enumerate() {
+                pixel.copy_from_slice(&f(row, col));
             }
         }
-    };
+        Ok(mat)
+    }
 }
 
-impl_mat!(u8, 1, DataTypes::CV_8U);
-impl_mat!(u8, 2, DataTypes::CV_8U);
-impl_mat!(u8, 3, DataTypes::CV_8U);
-impl_mat!(i8, 1, DataTypes::CV_8S);
-impl_mat!(i8, 2, DataTypes::CV_8S);
-impl_mat!(i8, 3, DataTypes::CV_8S);
-impl_mat!(u16, 1, DataTypes::CV_16U);
-impl_mat!(u16, 2, DataTypes::CV_16U);
-impl_mat!(u16, 3, DataTypes::CV_16U);
-impl_mat!(i16, 1, DataTypes::CV_16S);
-impl_mat!(i16, 2, DataTypes::CV_16S);
-impl_mat!(i16, 3, DataTypes::CV_16S);
-impl_mat!(i32, 1, DataTypes::CV_32S);
-impl_mat!(i32, 2, DataTypes::CV_32S);
-impl_mat!(i32, 3, DataTypes::CV_32S);
-impl_mat!(f32, 1, DataTypes::CV_32F);
-impl_mat!(f32, 2, DataTypes::CV_32F);
-impl_mat!(f32, 3, DataTypes::CV_32F);
-impl_mat!(f64, 1, DataTypes::CV_64F);
-impl_mat!(f64, 2, DataTypes::CV_64F);
-impl_mat!(f64, 3, DataTypes::CV_64F);
-
 impl<T, const C: usize> Mat<T, C> {
     pub fn new() -> Result<Self> {
         let pointer = Result::from(unsafe { ffi::cv_new_mat() })?;
@@ -108,23 +195,127 @@ impl<T, const C: usize> Mat<T, C> {
         }
     }
 
+    /// Freeze `self` into a read-only [`SharedMat`] whose headers can be shared.
+    pub fn into_shared(self) -> SharedMat<T, C> {
+        SharedMat { mat: self }
+    }
+
     pub fn data_type(&self) -> Option<DataTypes> {
-        let r#type = unsafe { ffi::cv_mat_type(self.pointer) };
-        DataTypes::from_bits(r#type)
+        DataTypes::from_bits(self.mat_type())
     }
 
+    /// The type OpenCV reports, which differs from `Self::TYPE` only if an
+    /// OpenCV call produced an output other than the one expected.
+    pub(crate) fn mat_type(&self) -> i32 {
+        unsafe { ffi::cv_mat_type(self.pointer) }
+    }
+
+    /// Whether the rows are stored without gaps. Views created by [`Mat::roi`]
+    /// and [`Mat::col_range`] usually are not.
+    pub fn is_continuous(&self) -> bool {
+        unsafe { ffi::cv_mat_is_continuous(self.pointer) }
+    }
+
+    /// All elements as one slice. Panics if the Mat is not continuous;
+    /// use [`Mat::row`] or [`Mat::at`] for views.
     pub fn data(&self) -> &[T] {
+        assert!(self.is_continuous(), "Mat is not continuous");
+        unsafe {
+            let size = ffi::cv_mat_size(self.pointer) as usize;
+            std::slice::from_raw_parts(self.data_ptr(), size)
+        }
+    }
+
+    pub fn data_mut(&mut self) -> &mut [T] {
+        assert!(self.is_continuous(), "Mat is not continuous");
         unsafe {
-            let data = ffi::cv_mat_data(self.pointer) as *const T;
             let size = ffi::cv_mat_size(self.pointer) as usize;
-            std::slice::from_raw_parts(data, size)
+            std::slice::from_raw_parts_mut(self.data_ptr() as *mut T, size)
+        }
+    }
+
+    /// Dangling rather than null for empty Mats, so that empty slices stay valid.
+    pub(crate) fn data_ptr(&self) -> *const T {
+        let data = unsafe { ffi::cv_mat_data(self.pointer) as *const T };
+        if data.is_null() {
+            NonNull::dangling().as_ptr()
+        } else {
+            data
+        }
+    }
+
+    /// Bytes between the starts of consecutive rows.
+    pub fn step(&self) -> usize {
+        unsafe { ffi::cv_mat_step(self.pointer) }
+    }
+
+    fn row_ptr(&self, row: usize) -> *const T {
+        assert!(
+            row < self.rows() as usize,
+            "row {} out of range for Mat with {} rows",
+            row,
+            self.rows()
+        );
+        let data = unsafe { ffi::cv_mat_data(self.pointer) as *const u8 };
+        if data.is_null() {
+            // Rows of a Mat with no columns have no buffer.
+            return NonNull::dangling().as_ptr();
         }
+        unsafe { data.add(row * self.step()) as *const T }
+    }
+
+    fn pixel_ptr(&self, row: usize, col: usize) -> *const T {
+        assert!(
+            col < self.cols() as usize,
+            "col {} out of range for Mat with {} cols",
+            col,
+            self.cols()
+        );
+        unsafe { self.row_ptr(row).add(col * C) }
+    }
+
+    /// Elements of one row, `cols * C` long. Rows are located through the Mat step.
+    pub fn row(&self, row: usize) -> &[T] {
+        let len = self.cols() as usize * C;
+        unsafe { std::slice::from_raw_parts(self.row_ptr(row), len) }
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        let len = self.cols() as usize * C;
+        unsafe { std::slice::from_raw_parts_mut(self.row_ptr(row) as *mut T, len) }
+    }
+
+    /// Pixel at `(row, col)`. Panics if out of bounds.
+    pub fn at(&self, row: usize, col: usize) -> &[T; C] {
+        unsafe { &*(self.pixel_ptr(row, col) as *const [T; C]) }
     }
 
+    pub fn at_mut(&mut self, row: usize, col: usize) -> &mut [T; C] {
+        unsafe { &mut *(self.pixel_ptr(row, col) as *mut [T; C]) }
+    }
+
+    /// Copy of the pixel at `(row, col)`, or `None` if out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<[T; C]>
+    where
+        T: Copy,
+    {
+        if row < self.rows() as usize && col < self.cols() as usize {
+            Some(*self.at(row, col))
+        } else {
+            None
+        }
+    }
+
+    /// Number of elements times channels.
     pub fn size(&self) -> i32 {
         unsafe { ffi::cv_mat_size(self.pointer) }
     }
 
+    /// 2 for every Mat built by this crate. Use [`MatNd`](super::MatNd) for more dimensions.
+    pub fn dims(&self) -> i32 {
+        unsafe { ffi::cv_mat_dims(self.pointer) }
+    }
+
     pub fn cols(&self) -> i32 {
         unsafe { ffi::cv_mat_cols(self.pointer) }
     }
@@ -138,12 +329,53 @@ impl<T, const C: usize> Mat<T, C> {
     }
 }
 
+impl<T, const C: usize> Clone for Mat<T, C> {
+    /// Deep copy via `cv::Mat::clone`.
+    fn clone(&self) -> Self {
+        let pointer = Result::from(unsafe { ffi::cv_mat_clone(self.pointer) })
+            .expect("cv::Mat::clone failed");
+        Self::from_ptr(pointer)
+    }
+}
+
+// cv::Mat keeps its buffer alive with an atomic reference count, so a Mat can be
+// moved to and released on another thread. Shared references only read the buffer.
+unsafe impl<T: Send, const C: usize> Send for Mat<T, C> {}
+unsafe impl<T: Sync, const C: usize> Sync for Mat<T, C> {}
+
 impl<T, const C: usize> Drop for Mat<T, C> {
     fn drop(&mut self) {
         unsafe { ffi::cv_release_mat(self.pointer) }
     }
 }
 
+/// A Mat that can no longer be written, created by [`Mat::into_shared`].
+///
+/// [`SharedMat::share`] copies only the ref-counted header, so each share can be
+/// moved to another thread and the buffer is freed with the last one. A `&Mat` is
+/// not enough to share from, since its owner could write the buffer afterwards.
+pub struct SharedMat<T, const C: usize> {
+    mat: Mat<T, C>,
+}
+
+impl<T, const C: usize> SharedMat<T, C> {
+    /// New header over the same buffer. No pixel data is copied.
+    pub fn share(&self) -> Result<Self> {
+        let pointer = Result::from(unsafe { ffi::cv_mat_share(self.mat.pointer) })?;
+        Ok(Self {
+            mat: Mat::from_ptr(pointer),
+        })
+    }
+}
+
+impl<T, const C: usize> Deref for SharedMat<T, C> {
+    type Target = Mat<T, C>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.mat
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -180,6 +412,126 @@ mod tests {
         assert_eq!(mat.channels(), 3);
     }
 
+    #[test]
+    fn clone_test() {
+        let src = Mat::mock_7x6_square_5x4();
+        let dst = src.clone();
+        assert_eq!(dst.data_type(), src.data_type());
+        assert_eq!(dst.data(), src.data());
+        assert_ne!(dst.data().as_ptr(), src.data().as_ptr());
+    }
+
+    #[test]
+    fn share_test() {
+        let src = Mat::mock_7x6_square_5x4().into_shared();
+        let dst = src.share().unwrap();
+        assert_eq!(dst.data().as_ptr(), src.data().as_ptr());
+        drop(src);
+        assert_eq!(dst.data().len(), 7 * 6 * 3);
+    }
+
+    #[test]
+    fn send_test() {
+        let src = Mat::mock_7x6_white();
+        let expected = src.clone();
+        let handle = std::thread::spawn(move || src.cvt_color_bgr2gray().unwrap());
+        let gray = handle.join().unwrap();
+        assert_eq!(gray.size(), 7 * 6);
+        assert!(expected.data().iter().all(|&v| v == 255));
+    }
+
+    #[test]
+    fn sync_test() {
+        let src = std::sync::Arc::new(Mat::mock_7x6_square_5x4());
+        let handles = (0..8)
+            .map(|_| {
+                let src = src.clone();
+                std::thread::spawn(move || {
+                    let copied = (*src).clone();
+                    assert_ne!(copied.data().as_ptr(), src.data().as_ptr());
+                    assert_eq!(copied.data(), src.data());
+                    copied
+                })
+            })
+            .collect::<Vec<_>>();
+        let copies = handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .collect::<Vec<_>>();
+        drop(src);
+        for mat in copies.iter() {
+            assert_eq!(mat.data().len(), 7 * 6 * 3);
+        }
+    }
+
+    #[test]
+    fn shared_send_test() {
+        let src = Mat::mock_7x6_square_5x4().into_shared();
+        let data = src.data().as_ptr() as usize;
+        let handles = (0..8)
+            .map(|_| {
+                let shared = src.share().unwrap();
+                std::thread::spawn(move || {
+                    assert_eq!(shared.data().as_ptr() as usize, data);
+                    shared
+                })
+            })
+            .collect::<Vec<_>>();
+        drop(src);
+        let shared = handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .collect::<Vec<_>>();
+        for mat in shared.iter() {
+            assert_eq!(mat.data().len(), 7 * 6 * 3);
+        }
+    }
+
+    #[test]
+    fn data_mut_test() {
+        let mut mat = Mat::mock_7x6_white();
+        mat.data_mut().iter_mut().for_each(|v| *v = 7);
+        assert!(mat.data().iter().all(|&v| v == 7));
+    }
+
+    #[test]
+    fn empty_row_test() {
+        let mut mat = Mat::<u8, 3>::from_shape(3, 0).unwrap();
+        assert_eq!(mat.rows(), 3);
+        assert!(mat.data().is_empty());
+        assert!(mat.row(2).is_empty());
+        assert!(mat.row_mut(0).is_empty());
+    }
+
+    #[test]
+    fn at_test() {
+        let mut mat = Mat::mock_7x6_white();
+        assert_eq!(mat.at(5, 6), &[255, 255, 255]);
+        *mat.at_mut(5, 6) = [1, 2, 3];
+        assert_eq!(mat.at(5, 6), &[1, 2, 3]);
+        assert_eq!(&mat.data()[mat.data().len() - 3..], &[1, 2, 3]);
+        assert_eq!(mat.get(5, 6), Some([1, 2, 3]));
+        assert_eq!(mat.get(6, 0), None);
+        assert_eq!(mat.get(0, 7), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn at_out_of_range_test() {
+        let mat = Mat::mock_7x6_white();
+        mat.at(6, 0);
+    }
+
+    #[test]
+    fn row_test() {
+        let mut mat = Mat::mock_7x6_white();
+        assert_eq!(mat.row(2).len(), 7 * 3);
+        mat.row_mut(2).iter_mut().for_each(|v| *v = 0);
+        assert!(mat.row(2).iter().all(|&v| v == 0));
+        assert!(mat.row(1).iter().all(|&v| v == 255));
+        assert_eq!(mat.at(2, 3), &[0, 0, 0]);
+    }
+
     #[test]
     fn ones_test1() {
         let mat = Mat::<u8, 1>::ones(4, 5).unwrap();
@@ -201,4 +553,56 @@ mod tests {
         assert_eq!(mat.data()[1], 0);
         assert_eq!(mat.data()[2], 0);
     }
+
+    #[test]
+    fn four_channel_test() {
+        let data = (0..4 * 3 * 4).map(|v| v as f32).collect::<Vec<_>>();
+        let mat = Mat::<f32, 4>::from_shape_vec(3, 4, &data).unwrap();
+        assert_eq!(mat.channels(), 4);
+        assert_eq!(mat.data_type(), Some(DataTypes::CV_32FC4));
+        assert_eq!(mat.at(1, 2), &[24., 25., 26., 27.]);
+    }
+
+    #[test]
+    fn wide_channel_test() {
+        let mat = Mat::<u8, 16>::from_shape(2, 3).unwrap();
+        assert_eq!(mat.channels(), 16);
+        assert_eq!(mat.size(), 2 * 3 * 16);
+        assert_eq!(mat.data_type(), None);
+    }
+
+    #[test]
+    fn from_shape_vec_copy_test() {
+        let mut data = vec![1u16, 2, 3, 4];
+        let mat = Mat::<u16, 1>::from_shape_vec(2, 2, &data).unwrap();
+        data[0] = 9;
+        drop(data);
+        assert_eq!(mat.data(), &[1, 2, 3, 4]);
+        assert!(Mat::<u16, 1>::from_shape_vec(2, 3, &[0; 4]).is_err());
+    }
+
+    #[test]
+    fn zeros_eye_test() {
+        let mat = Mat::<f32, 2>::zeros(2, 3).unwrap();
+        assert!(mat.data().iter().all(|&v| v == 0.));
+        let mat = Mat::<f64, 1>::eye(3, 3).unwrap();
+        assert_eq!(mat.data(), &[1., 0., 0., 0., 1., 0., 0., 0., 1.]);
+    }
+
+    #[test]
+    fn full_test() {
+        let mat = Mat::<u8, 3>::full(2, 2, [1, 2, 3]).unwrap();
+        assert_eq!(mat.data(), &[1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3]);
+        let mat = Mat::<u8, 3>::from_scalar(2, 2, Scalar::new(1., 2., 300., 4.)).unwrap();
+        assert_eq!(mat.at(1, 1), &[1, 2, 255]);
+    }
+
+    #[test]
+    fn from_fn_test() {
+        let mat = Mat::<i32, 2>::from_fn(3, 4, |r, c| [r as i32, c as i32]).unwrap();
+        assert_eq!(mat.rows(), 3);
+        assert_eq!(mat.cols(), 4);
+        assert_eq!(mat.at(2, 3), &[2, 3]);
+        assert_eq!(mat.at(1, 0), &[1, 0]);
+    }
 }
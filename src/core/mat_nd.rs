@@ -0,0 +1,393 @@
+//! Single-channel Mats with any number of dimensions, for DNN blobs, 3-D
+//! histograms and volumes. OpenCV stores 1-D shapes as `(n, 1)`.
+use super::{Depth, DynMat, Mat, MatPointer};
+use crate::result::{Error, Result};
+use ndarray::{
+    stack, ArrayBase, ArrayD, ArrayViewD, ArrayViewMutD, Axis, Data, Dimension, Ix4, IxDyn,
+    ShapeBuilder,
+};
+use std::{marker::PhantomData, mem::ManuallyDrop, ptr::NonNull};
+
+mod ffi {
+    use crate::{core::MatPointer, ffi::FFIResult};
+
+    #[link(name = "rxcv", kind = "static")]
+    extern "C" {
+        pub(super) fn cv_mat_nd_zeros(
+            dims: i32,
+            sizes: *const i32,
+            r#type: i32,
+        ) -> FFIResult<*const MatPointer>;
+        pub(super) fn cv_mat_nd_from_shape_vec(
+            dims: i32,
+            sizes: *const i32,
+            r#type: i32,
+            src: *const std::ffi::c_void,
+        ) -> FFIResult<*const MatPointer>;
+        pub(super) fn cv_mat_reshape_nd(
+            pointer: *const MatPointer,
+            cn: i32,
+            dims: i32,
+            sizes: *const i32,
+        ) -> FFIResult<*const MatPointer>;
+        pub(super) fn cv_mat_clone(pointer: *const MatPointer) -> FFIResult<*const MatPointer>;
+        pub(super) fn cv_mat_is_continuous(pointer: *const MatPointer) -> bool;
+        pub(super) fn cv_mat_data(pointer: *const MatPointer) -> *const std::ffi::c_void;
+        pub(super) fn cv_mat_size(pointer: *const MatPointer) -> i32;
+        pub(super) fn cv_mat_dims(pointer: *const MatPointer) -> i32;
+        pub(super) fn cv_mat_shape(pointer: *const MatPointer, sizes: *mut i32, steps: *mut usize);
+        pub(super) fn cv_release_mat(pointer: *const MatPointer);
+    }
+}
+
+fn sizes(shape: &[usize]) -> Result<Vec<i32>> {
+    if shape.is_empty() {
+        return Err(Error::InvalidArgument(
+            "MatNd needs at least one dimension".to_string(),
+        ));
+    }
+    Ok(shape.iter().map(|&n| n as i32).collect())
+}
+
+fn reshape(pointer: *const MatPointer, cn: usize, shape: &[usize]) -> Result<*const MatPointer> {
+    let sizes = sizes(shape)?;
+    Result::from(unsafe {
+        ffi::cv_mat_reshape_nd(pointer, cn as i32, sizes.len() as i32, sizes.as_ptr())
+    })
+}
+
+/// `cv::Mat` of `T` with one channel and `dims() >= 2` dimensions.
+pub struct MatNd<T> {
+    pub(crate) pointer: *const MatPointer,
+    data_type: PhantomData<T>,
+}
+
+impl<T: Depth> MatNd<T> {
+    pub fn zeros(shape: &[usize]) -> Result<Self> {
+        let sizes = sizes(shape)?;
+        let pointer = Result::from(unsafe {
+            ffi::cv_mat_nd_zeros(sizes.len() as i32, sizes.as_ptr(), T::DEPTH.bits())
+        })?;
+        Ok(Self::from_ptr(pointer))
+    }
+
+    /// Copy `data`, in row-major order, into a new Mat.
+    pub fn from_shape_vec(shape: &[usize], data: &[T]) -> Result<Self> {
+        let sizes = sizes(shape)?;
+        if shape.iter().product::<usize>() != data.len() {
+            return Err(Error::InvalidArgument(format!(
+                "{} elements do not fill a MatNd of shape {:?}",
+                data.len(),
+                shape
+            )));
+        }
+        let pointer = Result::from(unsafe {
+            ffi::cv_mat_nd_from_shape_vec(
+                sizes.len() as i32,
+                sizes.as_ptr(),
+                T::DEPTH.bits(),
+                data.as_ptr() as *const std::ffi::c_void,
+            )
+        })?;
+        Ok(Self::from_ptr(pointer))
+    }
+
+    /// Copy an array of any dimension and memory layout into a new Mat.
+    pub fn from_ndarray<S, D>(array: &ArrayBase<S, D>) -> Result<Self>
+    where
+        S: Data<Elem = T>,
+        D: Dimension,
+    {
+        let array = array.as_standard_layout();
+        Self::from_shape_vec(array.shape(), array.as_slice().unwrap())
+    }
+
+    /// Pack same-sized images into an `(N, C, rows, cols)` blob, the layout DNN
+    /// models expect.
+    pub fn from_images<const C: usize>(images: &[&Mat<T, C>]) -> Result<Self> {
+        let views = images
+            .iter()
+            .map(|image| image.as_array_view())
+            .collect::<Vec<_>>();
+        let batch = stack(Axis(0), &views).map_err(|e| {
+            Error::InvalidArgument(format!("cannot stack {} images: {}", images.len(), e))
+        })?;
+        Self::from_ndarray(&batch.permuted_axes([0, 3, 1, 2]))
+    }
+
+    /// Split an `(N, C, rows, cols)` blob back into images.
+    pub fn to_images<const C: usize>(&self) -> Result<Vec<Mat<T, C>>> {
+        let shape = self.shape();
+        if shape.len() != 4 || shape[1] != C {
+            return Err(Error::InvalidArgument(format!(
+                "MatNd of shape {:?} is not an (N, {}, rows, cols) blob",
+                shape, C
+            )));
+        }
+        let view = self.as_array_view().into_dimensionality::<Ix4>().unwrap();
+        view.outer_iter()
+            .map(|image| Mat::from_ndarray(&image.permuted_axes([1, 2, 0])))
+            .collect()
+    }
+
+    /// Reinterpret a `(rows, cols, C)` Mat as `Mat<T, C>` without copying.
+    /// Consumes `self` so that only one header can write the buffer.
+    pub fn reshape_mat<const C: usize>(self) -> Result<Mat<T, C>> {
+        let shape = self.shape();
+        if shape.len() != 3 || shape[2] != C {
+            return Err(Error::InvalidArgument(format!(
+                "MatNd of shape {:?} does not hold {} channels",
+                shape, C
+            )));
+        }
+        let pointer = reshape(self.pointer, C, &shape[..2])?;
+        Ok(Mat::from_ptr(pointer))
+    }
+}
+
+impl<T> MatNd<T> {
+    pub(crate) fn from_ptr(pointer: *const MatPointer) -> Self {
+        Self {
+            pointer,
+            data_type: PhantomData,
+        }
+    }
+
+    pub fn dims(&self) -> usize {
+        unsafe { ffi::cv_mat_dims(self.pointer) as usize }
+    }
+
+    fn layout(&self) -> (Vec<usize>, Vec<usize>) {
+        let dims = self.dims();
+        let mut sizes = vec![0; dims];
+        let mut steps = vec![0; dims];
+        unsafe { ffi::cv_mat_shape(self.pointer, sizes.as_mut_ptr(), steps.as_mut_ptr()) };
+        (sizes.into_iter().map(|n| n as usize).collect(), steps)
+    }
+
+    pub fn shape(&self) -> Vec<usize> {
+        self.layout().0
+    }
+
+    /// Bytes between consecutive indices of each dimension.
+    pub fn step(&self) -> Vec<usize> {
+        self.layout().1
+    }
+
+    pub fn total(&self) -> usize {
+        unsafe { ffi::cv_mat_size(self.pointer) as usize }
+    }
+
+    pub fn is_continuous(&self) -> bool {
+        unsafe { ffi::cv_mat_is_continuous(self.pointer) }
+    }
+
+    fn data_ptr(&self) -> *mut T {
+        let data = unsafe { ffi::cv_mat_data(self.pointer) as *mut T };
+        if data.is_null() {
+            NonNull::dangling().as_ptr()
+        } else {
+            data
+        }
+    }
+
+    /// All elements in row-major order.
+    pub fn data(&self) -> &[T] {
+        assert!(self.is_continuous(), "Mat is not continuous");
+        unsafe { std::slice::from_raw_parts(self.data_ptr(), self.total()) }
+    }
+
+    pub fn data_mut(&mut self) -> &mut [T] {
+        assert!(self.is_continuous(), "Mat is not continuous");
+        unsafe { std::slice::from_raw_parts_mut(self.data_ptr(), self.total()) }
+    }
+
+    fn array_shape(&self) -> ndarray::StrideShape<IxDyn> {
+        let (shape, steps) = self.layout();
+        let strides = steps
+            .iter()
+            .map(|step| step / std::mem::size_of::<T>())
+            .collect::<Vec<_>>();
+        IxDyn(&shape).strides(IxDyn(&strides))
+    }
+
+    /// Borrow the Mat buffer as an array without copying.
+    pub fn as_array_view(&self) -> ArrayViewD<'_, T> {
+        unsafe { ArrayViewD::from_shape_ptr(self.array_shape(), self.data_ptr()) }
+    }
+
+    pub fn as_array_view_mut(&mut self) -> ArrayViewMutD<'_, T> {
+        unsafe { ArrayViewMutD::from_shape_ptr(self.array_shape(), self.data_ptr()) }
+    }
+
+    pub fn to_ndarray(&self) -> ArrayD<T>
+    where
+        T: Clone,
+    {
+        self.as_array_view().to_owned()
+    }
+}
+
+impl<T: Depth, const C: usize> Mat<T, C> {
+    /// Reinterpret as a `(rows, cols, C)` [`MatNd`] without copying. The Mat must be
+    /// continuous. Consumes `self` so that only one header can write the buffer.
+    pub fn reshape_nd(self) -> Result<MatNd<T>> {
+        let shape = [self.rows() as usize, self.cols() as usize, C];
+        let pointer = reshape(self.pointer, 1, &shape)?;
+        Ok(MatNd::from_ptr(pointer))
+    }
+}
+
+impl<T> Clone for MatNd<T> {
+    /// Deep copy via `cv::Mat::clone`.
+    fn clone(&self) -> Self {
+        let pointer = Result::from(unsafe { ffi::cv_mat_clone(self.pointer) })
+            .expect("cv::Mat::clone failed");
+        Self::from_ptr(pointer)
+    }
+}
+
+// Same reasoning as for `Mat`.
+unsafe impl<T: Send> Send for MatNd<T> {}
+unsafe impl<T: Sync> Sync for MatNd<T> {}
+
+impl<T> Drop for MatNd<T> {
+    fn drop(&mut self) {
+        unsafe { ffi::cv_release_mat(self.pointer) }
+    }
+}
+
+impl<T> From<MatNd<T>> for DynMat {
+    fn from(mat: MatNd<T>) -> Self {
+        let mat = ManuallyDrop::new(mat);
+        DynMat::from_ptr(mat.pointer)
+    }
+}
+
+impl<T: Depth> TryFrom<DynMat> for MatNd<T> {
+    type Error = Error;
+
+    /// Fails with [`Error::TypeMismatch`] unless the Mat has one channel of `T`, and
+    /// with [`Error::InvalidArgument`] for empty Mats, which may have no dimensions.
+    fn try_from(mat: DynMat) -> Result<Self> {
+        if mat.mat_type() != T::DEPTH.bits() {
+            return Err(Error::TypeMismatch {
+                expected: T::DEPTH.bits(),
+                actual: mat.mat_type(),
+            });
+        }
+        let total = unsafe { ffi::cv_mat_size(mat.pointer) };
+        if mat.dims() < 2 || total == 0 {
+            return Err(Error::InvalidArgument(format!(
+                "cannot view an empty {}-d Mat as a MatNd",
+                mat.dims()
+            )));
+        }
+        let mat = ManuallyDrop::new(mat);
+        Ok(MatNd::from_ptr(mat.pointer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DataTypes;
+    use ndarray::{s, Array};
+
+    #[test]
+    fn ndarray_test() {
+        let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 12 + j * 4 + k) as f32);
+        let mat = MatNd::from_ndarray(&a).unwrap();
+        assert_eq!(mat.dims(), 3);
+        assert_eq!(mat.shape(), vec![2, 3, 4]);
+        assert_eq!(mat.step(), vec![48, 16, 4]);
+        assert_eq!(mat.total(), 24);
+        assert_eq!(mat.data(), a.as_slice().unwrap());
+        assert_eq!(mat.to_ndarray(), a.clone().into_dyn());
+
+        let t = a.view().permuted_axes([2, 0, 1]);
+        let mat = MatNd::from_ndarray(&t.slice(s![1.., .., ..])).unwrap();
+        assert_eq!(mat.shape(), vec![3, 2, 3]);
+        assert_eq!(mat.as_array_view()[[0, 1, 2]], a[[1, 2, 1]]);
+
+        assert!(MatNd::<u8>::from_shape_vec(&[2, 2, 2], &[0; 7]).is_err());
+        assert!(MatNd::<u8>::zeros(&[]).is_err());
+    }
+
+    #[test]
+    fn zeros_test() {
+        let mut mat = MatNd::<i32>::zeros(&[2, 2, 2, 2]).unwrap();
+        assert!(mat.data().iter().all(|&v| v == 0));
+        mat.as_array_view_mut()[[1, 0, 1, 0]] = 7;
+        assert_eq!(mat.data()[10], 7);
+        let mat = MatNd::<u8>::zeros(&[5]).unwrap();
+        assert_eq!(mat.shape(), vec![5, 1]);
+    }
+
+    #[test]
+    fn images_test() {
+        let a = Mat::<u8, 3>::from_fn(2, 3, |r, c| [r as u8, c as u8, 9]).unwrap();
+        let b = Mat::<u8, 3>::full(2, 3, [1, 2, 3]).unwrap();
+        let blob = MatNd::from_images(&[&a, &b]).unwrap();
+        assert_eq!(blob.shape(), vec![2, 3, 2, 3]);
+        let view = blob.as_array_view();
+        assert_eq!(view[[0, 1, 1, 2]], 2);
+        assert_eq!(view[[0, 2, 0, 0]], 9);
+        assert_eq!(view[[1, 2, 1, 1]], 3);
+
+        let images = blob.to_images::<3>().unwrap();
+        assert_eq!(images.len(), 2);
+        assert_eq!(images[0].data(), a.data());
+        assert_eq!(images[1].data(), b.data());
+        assert!(blob.to_images::<1>().is_err());
+
+        let c = Mat::<u8, 3>::zeros(3, 3).unwrap();
+        assert!(MatNd::from_images(&[&a, &c]).is_err());
+        assert!(MatNd::<u8>::from_images::<3>(&[]).is_err());
+    }
+
+    #[test]
+    fn reshape_test() {
+        let src = Mat::<u16, 2>::from_fn(3, 4, |r, c| [r as u16, c as u16]).unwrap();
+        assert_eq!(src.dims(), 2);
+        let data = src.data().as_ptr();
+        let mut nd = src.reshape_nd().unwrap();
+        assert_eq!(nd.shape(), vec![3, 4, 2]);
+        assert_eq!(nd.as_array_view()[[2, 1, 0]], 2);
+        assert_eq!(nd.data().as_ptr(), data);
+        nd.data_mut()[0] = 42;
+
+        assert!(nd.clone().reshape_mat::<3>().is_err());
+        let back = nd.reshape_mat::<2>().unwrap();
+        assert_eq!((back.rows(), back.cols()), (3, 4));
+        assert_eq!(back.data().as_ptr(), data);
+        assert_eq!(back.at(0, 0), &[42, 0]);
+        assert_eq!(back.at(2, 3), &[2, 3]);
+    }
+
+    #[test]
+    fn dyn_mat_test() {
+        let mat = DynMat::from(MatNd::<f64>::zeros(&[2, 3, 4]).unwrap());
+        assert_eq!(mat.dims(), 3);
+        assert_eq!((mat.rows(), mat.cols()), (-1, -1));
+        assert_eq!(mat.data_type(), Some(DataTypes::CV_64FC1));
+        assert!(MatNd::<f32>::try_from(mat.clone()).is_err());
+        let mat = MatNd::<f64>::try_from(mat).unwrap();
+        assert_eq!(mat.total(), 24);
+    }
+
+    #[test]
+    fn dyn_mat_empty_test() {
+        let empty = DynMat::from(Mat::<u8, 1>::new().unwrap());
+        assert_eq!(empty.dims(), 0);
+        assert!(matches!(
+            MatNd::<u8>::try_from(empty),
+            Err(Error::InvalidArgument(_))
+        ));
+        let empty = DynMat::from(Mat::<u8, 1>::zeros(0, 3).unwrap());
+        assert!(matches!(
+            MatNd::<u8>::try_from(empty),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+}
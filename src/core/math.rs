@@ -0,0 +1,285 @@
+//! Normalization and per-element math. Angles are in radians unless
+//! `angle_in_degrees` is set.
+use super::{arithm::mask_pointer, Depth, Mat, NormTypes, Point};
+use crate::result::Result;
+
+mod ffi {
+    use crate::{
+        core::{MatPointer, Point},
+        ffi::FFIResult,
+    };
+
+    #[link(name = "rxcv", kind = "static")]
+    extern "C" {
+        pub(super) fn cv_normalize(
+            src: *const MatPointer,
+            dst: *const MatPointer,
+            alpha: f64,
+            beta: f64,
+            norm_type: i32,
+            mask: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_exp(src: *const MatPointer, dst: *const MatPointer) -> FFIResult<i32>;
+        pub(super) fn cv_log(src: *const MatPointer, dst: *const MatPointer) -> FFIResult<i32>;
+        pub(super) fn cv_pow(
+            src: *const MatPointer,
+            power: f64,
+            dst: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_sqrt(src: *const MatPointer, dst: *const MatPointer) -> FFIResult<i32>;
+        pub(super) fn cv_magnitude(
+            x: *const MatPointer,
+            y: *const MatPointer,
+            magnitude: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_phase(
+            x: *const MatPointer,
+            y: *const MatPointer,
+            angle: *const MatPointer,
+            angle_in_degrees: bool,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_cart_to_polar(
+            x: *const MatPointer,
+            y: *const MatPointer,
+            magnitude: *const MatPointer,
+            angle: *const MatPointer,
+            angle_in_degrees: bool,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_polar_to_cart(
+            magnitude: *const MatPointer,
+            angle: *const MatPointer,
+            x: *const MatPointer,
+            y: *const MatPointer,
+            angle_in_degrees: bool,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_patch_nans(a: *const MatPointer, val: f64) -> FFIResult<i32>;
+        pub(super) fn cv_check_range(
+            src: *const MatPointer,
+            pos: *mut Point,
+            min_val: f64,
+            max_val: f64,
+        ) -> FFIResult<i32>;
+    }
+}
+
+impl<T: Depth, const C: usize> Mat<T, C> {
+    /// Scale to norm `alpha`, or with `NORM_MINMAX` to the range `alpha..=beta`.
+    /// Elements outside `mask` are zero.
+    pub fn normalize(
+        &self,
+        alpha: f64,
+        beta: f64,
+        norm_type: NormTypes,
+        mask: Option<&Mat<u8, 1>>,
+    ) -> Result<Self> {
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_normalize(
+                self.pointer,
+                dst.pointer,
+                alpha,
+                beta,
+                norm_type.bits(),
+                mask_pointer(mask),
+            )
+        })?;
+        Ok(dst)
+    }
+
+    /// Location of the first element that is NaN, infinite or outside `min..max`,
+    /// or `None` if there is none.
+    pub fn check_range(&self, min: f64, max: f64) -> Result<Option<Point>> {
+        let mut pos = Point::default();
+        let ok = Result::from(unsafe { ffi::cv_check_range(self.pointer, &mut pos, min, max) })?;
+        Ok((ok == 0).then_some(pos))
+    }
+}
+
+macro_rules! impl_math {
+    ($t:ty) => {
+        impl<const C: usize> Mat<$t, C> {
+            pub fn exp(&self) -> Result<Self> {
+                let dst = Mat::new()?;
+                Result::from(unsafe { ffi::cv_exp(self.pointer, dst.pointer) })?;
+                Ok(dst)
+            }
+
+            /// Natural logarithm of the absolute value.
+            pub fn log(&self) -> Result<Self> {
+                let dst = Mat::new()?;
+                Result::from(unsafe { ffi::cv_log(self.pointer, dst.pointer) })?;
+                Ok(dst)
+            }
+
+            /// Raise to `power`. Non-integer powers use the absolute value.
+            pub fn pow(&self, power: f64) -> Result<Self> {
+                let dst = Mat::new()?;
+                Result::from(unsafe { ffi::cv_pow(self.pointer, power, dst.pointer) })?;
+                Ok(dst)
+            }
+
+            pub fn sqrt(&self) -> Result<Self> {
+                let dst = Mat::new()?;
+                Result::from(unsafe { ffi::cv_sqrt(self.pointer, dst.pointer) })?;
+                Ok(dst)
+            }
+
+            /// Replace every NaN with `value`.
+            pub fn patch_nans(&mut self, value: f64) -> Result<()> {
+                Result::from(unsafe { ffi::cv_patch_nans(self.pointer, value) })?;
+                Ok(())
+            }
+        }
+
+        impl Mat<$t, 1> {
+            /// `sqrt(x^2 + y^2)` with `self` as x.
+            pub fn magnitude(&self, y: &Self) -> Result<Self> {
+                let dst = Mat::new()?;
+                Result::from(unsafe { ffi::cv_magnitude(self.pointer, y.pointer, dst.pointer) })?;
+                Ok(dst)
+            }
+
+            /// `atan2(y, x)` in `0..2π` (or `0..360`) with `self` as x, accurate to
+            /// about 0.3 degrees.
+            pub fn phase(&self, y: &Self, angle_in_degrees: bool) -> Result<Self> {
+                let dst = Mat::new()?;
+                Result::from(unsafe {
+                    ffi::cv_phase(self.pointer, y.pointer, dst.pointer, angle_in_degrees)
+                })?;
+                Ok(dst)
+            }
+
+            /// `(magnitude, angle)` of the vectors `(self, y)`.
+            pub fn cart_to_polar(&self, y: &Self, angle_in_degrees: bool) -> Result<(Self, Self)> {
+                let magnitude = Mat::new()?;
+                let angle = Mat::new()?;
+                Result::from(unsafe {
+                    ffi::cv_cart_to_polar(
+                        self.pointer,
+                        y.pointer,
+                        magnitude.pointer,
+                        angle.pointer,
+                        angle_in_degrees,
+                    )
+                })?;
+                Ok((magnitude, angle))
+            }
+
+            /// `(x, y)` of the vectors with magnitude `self` and direction `angle`.
+            pub fn polar_to_cart(
+                &self,
+                angle: &Self,
+                angle_in_degrees: bool,
+            ) -> Result<(Self, Self)> {
+                let x = Mat::new()?;
+                let y = Mat::new()?;
+                Result::from(unsafe {
+                    ffi::cv_polar_to_cart(
+                        self.pointer,
+                        angle.pointer,
+                        x.pointer,
+                        y.pointer,
+                        angle_in_degrees,
+                    )
+                })?;
+                Ok((x, y))
+            }
+        }
+    };
+}
+
+impl_math!(f32);
+impl_math!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{imgproc::Sobel, BorderTypes};
+
+    fn mat(data: &[f32]) -> Mat<f32, 1> {
+        Mat::from_shape_vec(1, data.len(), data).unwrap()
+    }
+
+    fn assert_close(a: &[f32], b: &[f32], eps: f32) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).abs() < eps, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn normalize_test() {
+        let src = mat(&[1., 2., 3.]);
+        let dst = src
+            .normalize(0., 10., NormTypes::NORM_MINMAX, None)
+            .unwrap();
+        assert_close(dst.data(), &[0., 5., 10.], 1e-5);
+        let dst = mat(&[3., 4.])
+            .normalize(1., 0., NormTypes::NORM_L2, None)
+            .unwrap();
+        assert_close(dst.data(), &[0.6, 0.8], 1e-6);
+
+        let gray = Mat::mock_7x6_square_5x4().cvt_color_bgr2gray().unwrap();
+        let dst = gray
+            .normalize(0., 1., NormTypes::NORM_MINMAX, None)
+            .unwrap();
+        assert_eq!(dst.at(1, 1), &[1]);
+        assert_eq!(dst.at(0, 0), &[0]);
+    }
+
+    #[test]
+    fn elementwise_test() {
+        let src = mat(&[1., 4., 9.]);
+        assert_close(src.sqrt().unwrap().data(), &[1., 2., 3.], 1e-6);
+        assert_close(src.pow(2.).unwrap().data(), &[1., 16., 81.], 1e-4);
+        assert_close(src.log().unwrap().exp().unwrap().data(), src.data(), 1e-4);
+        let src = Mat::<f64, 2>::from_shape_vec(1, 1, &[0., 1.]).unwrap();
+        assert_eq!(src.exp().unwrap().at(0, 0)[0], 1.);
+    }
+
+    #[test]
+    fn polar_test() {
+        let x = mat(&[3., 0., -1.]);
+        let y = mat(&[4., 2., 0.]);
+        assert_close(x.magnitude(&y).unwrap().data(), &[5., 2., 1.], 1e-5);
+        let (magnitude, angle) = x.cart_to_polar(&y, true).unwrap();
+        assert_close(magnitude.data(), &[5., 2., 1.], 1e-5);
+        assert_close(angle.data(), &[53.13, 90., 180.], 0.5);
+        assert_close(x.phase(&y, true).unwrap().data(), angle.data(), 1e-3);
+
+        let (bx, by) = magnitude.polar_to_cart(&angle, true).unwrap();
+        assert_close(bx.data(), x.data(), 0.05);
+        assert_close(by.data(), y.data(), 0.05);
+    }
+
+    #[test]
+    fn gradient_test() {
+        let gray = Mat::mock_7x6_square_5x4().cvt_color_bgr2gray().unwrap();
+        let gray: Mat<f32, 1> = gray.convert_to(1. / 255., 0.).unwrap();
+        let dx: Mat<f32, 1> = gray
+            .sobel(1, 0, 3, 1., 0., BorderTypes::BORDER_DEFAULT)
+            .unwrap();
+        let dy: Mat<f32, 1> = gray
+            .sobel(0, 1, 3, 1., 0., BorderTypes::BORDER_DEFAULT)
+            .unwrap();
+        let (magnitude, angle) = dx.cart_to_polar(&dy, true).unwrap();
+        assert_eq!(magnitude.at(3, 3), &[0.]);
+        assert!(magnitude.at(3, 1)[0] > 0.);
+        // Entering the square from the left the intensity rises along +x.
+        assert!(angle.at(3, 1)[0] < 1. || angle.at(3, 1)[0] > 359.);
+    }
+
+    #[test]
+    fn patch_nans_check_range_test() {
+        let mut src = mat(&[1., f32::NAN, 3.]);
+        assert_eq!(src.check_range(-10., 10.).unwrap(), Some(Point::new(1, 0)));
+        src.patch_nans(0.).unwrap();
+        assert_eq!(src.data(), &[1., 0., 3.]);
+        assert_eq!(src.check_range(-10., 10.).unwrap(), None);
+        assert_eq!(src.check_range(0., 2.).unwrap(), Some(Point::new(2, 0)));
+
+        let mut src = Mat::<f64, 2>::from_shape_vec(1, 2, &[f64::NAN, 1., 2., f64::NAN]).unwrap();
+        src.patch_nans(-1.).unwrap();
+        assert_eq!(src.data(), &[-1., 1., 2., -1.]);
+    }
+}
@@ -1,12 +1,40 @@
 //! OpenCV Core Module
+mod arithm;
 mod array;
+mod bitwise;
 mod bytes;
+mod channels;
 mod consts;
 mod contours;
+mod convert;
+mod dft;
+mod dyn_mat;
+mod format;
+mod half;
+mod iter;
+mod layout;
+mod linalg;
 mod mat;
+mod mat_nd;
+mod math;
+mod persistence;
+mod rand;
+mod stat;
+mod types;
+mod view;
 
 pub use array::*;
 pub use bytes::*;
 pub use consts::*;
 pub use contours::*;
+pub use dft::*;
+pub use dyn_mat::*;
+pub use format::*;
+pub use half::*;
+pub use iter::*;
 pub use mat::*;
+pub use mat_nd::*;
+pub use persistence::*;
+pub use rand::*;
+pub use types::*;
+pub use view::*;
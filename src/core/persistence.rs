@@ -0,0 +1,512 @@
+//! XML, YAML and JSON storage through `cv::FileStorage`.
+use super::{Bytes, Depth, DynMat, FileNodeType, FileStorageMode, Mat};
+use crate::result::{Error, Result};
+use std::{ffi::CString, fmt, marker::PhantomData};
+
+mod ffi {
+    use super::{FileNodePointer, FileStoragePointer};
+    use crate::{
+        core::{BytesPointer, MatPointer},
+        ffi::FFIResult,
+    };
+    use std::ffi::c_char;
+
+    #[link(name = "rxcv", kind = "static")]
+    extern "C" {
+        pub(super) fn cv_file_storage_open(
+            source: *const c_char,
+            flags: i32,
+        ) -> FFIResult<*const FileStoragePointer>;
+        pub(super) fn cv_release_file_storage(fs: *const FileStoragePointer);
+        pub(super) fn cv_file_storage_release(fs: *const FileStoragePointer) -> FFIResult<i32>;
+        pub(super) fn cv_file_storage_release_and_get_string(
+            fs: *const FileStoragePointer,
+            dst: *const BytesPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_file_storage_write_int(
+            fs: *const FileStoragePointer,
+            name: *const c_char,
+            value: i32,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_file_storage_write_real(
+            fs: *const FileStoragePointer,
+            name: *const c_char,
+            value: f64,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_file_storage_write_string(
+            fs: *const FileStoragePointer,
+            name: *const c_char,
+            value: *const c_char,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_file_storage_write_mat(
+            fs: *const FileStoragePointer,
+            name: *const c_char,
+            value: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_file_storage_start_write_struct(
+            fs: *const FileStoragePointer,
+            name: *const c_char,
+            flags: i32,
+            type_name: *const c_char,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_file_storage_end_write_struct(
+            fs: *const FileStoragePointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_file_storage_root(
+            fs: *const FileStoragePointer,
+        ) -> FFIResult<*const FileNodePointer>;
+
+        pub(super) fn cv_release_file_node(node: *const FileNodePointer);
+        pub(super) fn cv_file_node_type(node: *const FileNodePointer) -> i32;
+        pub(super) fn cv_file_node_size(node: *const FileNodePointer) -> usize;
+        pub(super) fn cv_file_node_name(
+            node: *const FileNodePointer,
+            dst: *const BytesPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_file_node_get(
+            node: *const FileNodePointer,
+            name: *const c_char,
+        ) -> FFIResult<*const FileNodePointer>;
+        pub(super) fn cv_file_node_at(
+            node: *const FileNodePointer,
+            index: usize,
+        ) -> FFIResult<*const FileNodePointer>;
+        pub(super) fn cv_file_node_int(node: *const FileNodePointer) -> FFIResult<i32>;
+        pub(super) fn cv_file_node_real(node: *const FileNodePointer) -> FFIResult<f64>;
+        pub(super) fn cv_file_node_string(
+            node: *const FileNodePointer,
+            dst: *const BytesPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_file_node_mat(
+            node: *const FileNodePointer,
+            dst: *const MatPointer,
+        ) -> FFIResult<i32>;
+    }
+}
+
+pub(crate) enum FileStoragePointer {}
+pub(crate) enum FileNodePointer {}
+
+fn c_string(s: &str) -> Result<CString> {
+    CString::new(s).map_err(|_| Error::InvalidArgument(format!("{:?} contains a nul byte", s)))
+}
+
+fn bytes_to_string(bytes: &Bytes) -> String {
+    String::from_utf8_lossy(bytes.data()).into_owned()
+}
+
+/// A file or in-memory document opened for reading or writing.
+///
+/// Values are written under a name, which must be empty inside a sequence.
+/// Nested sequences and maps are written between
+/// [`start_write_struct`](Self::start_write_struct) and
+/// [`end_write_struct`](Self::end_write_struct).
+pub struct FileStorage {
+    pointer: *const FileStoragePointer,
+}
+
+impl FileStorage {
+    /// Open `path`. Unless a `FORMAT_*` flag is given the format follows the
+    /// extension: `.xml`, `.json`, and YAML otherwise.
+    pub fn open(path: &str, flags: FileStorageMode) -> Result<Self> {
+        let path = c_string(path)?;
+        let pointer =
+            Result::from(unsafe { ffi::cv_file_storage_open(path.as_ptr(), flags.bits()) })?;
+        Ok(Self { pointer })
+    }
+
+    /// Read a document held in `content`, of any supported format.
+    pub fn from_memory(content: &str) -> Result<Self> {
+        Self::open(content, FileStorageMode::READ | FileStorageMode::MEMORY)
+    }
+
+    /// Write a document in `format` (a `FORMAT_*` flag), to be collected with
+    /// [`release_and_get_string`](Self::release_and_get_string).
+    pub fn new_in_memory(format: FileStorageMode) -> Result<Self> {
+        let format = format & FileStorageMode::FORMAT_MASK;
+        let name = if format == FileStorageMode::FORMAT_XML {
+            ".xml"
+        } else if format == FileStorageMode::FORMAT_JSON {
+            ".json"
+        } else {
+            ".yml"
+        };
+        Self::open(
+            name,
+            FileStorageMode::WRITE | FileStorageMode::MEMORY | format,
+        )
+    }
+
+    /// Close the storage, flushing a file being written.
+    pub fn release(self) -> Result<()> {
+        Result::from(unsafe { ffi::cv_file_storage_release(self.pointer) })?;
+        Ok(())
+    }
+
+    /// Close an in-memory storage and return the written document.
+    pub fn release_and_get_string(self) -> Result<String> {
+        let bytes = Bytes::default();
+        Result::from(unsafe {
+            ffi::cv_file_storage_release_and_get_string(self.pointer, bytes.pointer)
+        })?;
+        Ok(bytes_to_string(&bytes))
+    }
+
+    pub fn write_i32(&mut self, name: &str, value: i32) -> Result<()> {
+        let name = c_string(name)?;
+        Result::from(unsafe {
+            ffi::cv_file_storage_write_int(self.pointer, name.as_ptr(), value)
+        })?;
+        Ok(())
+    }
+
+    pub fn write_f64(&mut self, name: &str, value: f64) -> Result<()> {
+        let name = c_string(name)?;
+        Result::from(unsafe {
+            ffi::cv_file_storage_write_real(self.pointer, name.as_ptr(), value)
+        })?;
+        Ok(())
+    }
+
+    pub fn write_str(&mut self, name: &str, value: &str) -> Result<()> {
+        let name = c_string(name)?;
+        let value = c_string(value)?;
+        Result::from(unsafe {
+            ffi::cv_file_storage_write_string(self.pointer, name.as_ptr(), value.as_ptr())
+        })?;
+        Ok(())
+    }
+
+    /// Write `mat` as an `opencv-matrix` map.
+    pub fn write_mat<T: Depth, const C: usize>(
+        &mut self,
+        name: &str,
+        mat: &Mat<T, C>,
+    ) -> Result<()> {
+        let name = c_string(name)?;
+        Result::from(unsafe {
+            ffi::cv_file_storage_write_mat(self.pointer, name.as_ptr(), mat.pointer)
+        })?;
+        Ok(())
+    }
+
+    /// Begin a `SEQ` or `MAP`, optionally with `FLOW` for the inline style.
+    /// `type_name` is usually empty.
+    pub fn start_write_struct(
+        &mut self,
+        name: &str,
+        flags: FileNodeType,
+        type_name: &str,
+    ) -> Result<()> {
+        let name = c_string(name)?;
+        let type_name = c_string(type_name)?;
+        Result::from(unsafe {
+            ffi::cv_file_storage_start_write_struct(
+                self.pointer,
+                name.as_ptr(),
+                flags.bits(),
+                type_name.as_ptr(),
+            )
+        })?;
+        Ok(())
+    }
+
+    pub fn end_write_struct(&mut self) -> Result<()> {
+        Result::from(unsafe { ffi::cv_file_storage_end_write_struct(self.pointer) })?;
+        Ok(())
+    }
+
+    /// The top-level map of a storage opened for reading.
+    pub fn root(&self) -> Result<FileNode<'_>> {
+        let pointer = Result::from(unsafe { ffi::cv_file_storage_root(self.pointer) })?;
+        Ok(FileNode::from_ptr(pointer))
+    }
+
+    /// Top-level entry `name`, a `NONE` node if there is none.
+    pub fn get(&self, name: &str) -> Result<FileNode<'_>> {
+        self.root()?.get(name)
+    }
+}
+
+impl Drop for FileStorage {
+    fn drop(&mut self) {
+        unsafe { ffi::cv_release_file_storage(self.pointer) }
+    }
+}
+
+/// An element of a [`FileStorage`] being read.
+pub struct FileNode<'a> {
+    pointer: *const FileNodePointer,
+    marker: PhantomData<&'a FileStorage>,
+}
+
+impl<'a> FileNode<'a> {
+    fn from_ptr(pointer: *const FileNodePointer) -> Self {
+        Self {
+            pointer,
+            marker: PhantomData,
+        }
+    }
+
+    /// The node kind in `TYPE_MASK` together with any `FLOW`, `EMPTY` or `NAMED` bits.
+    pub fn node_type(&self) -> FileNodeType {
+        FileNodeType::from_bits_truncate(unsafe { ffi::cv_file_node_type(self.pointer) })
+    }
+
+    fn kind(&self) -> FileNodeType {
+        self.node_type() & FileNodeType::TYPE_MASK
+    }
+
+    fn expect(&self, kind: FileNodeType) -> Result<()> {
+        if self.kind() == kind {
+            Ok(())
+        } else {
+            Err(Error::NodeTypeMismatch {
+                expected: kind,
+                actual: self.kind(),
+            })
+        }
+    }
+
+    pub fn is_none(&self) -> bool {
+        self.kind() == FileNodeType::NONE
+    }
+
+    pub fn is_int(&self) -> bool {
+        self.kind() == FileNodeType::INT
+    }
+
+    pub fn is_real(&self) -> bool {
+        self.kind() == FileNodeType::REAL
+    }
+
+    pub fn is_string(&self) -> bool {
+        self.kind() == FileNodeType::STR
+    }
+
+    pub fn is_seq(&self) -> bool {
+        self.kind() == FileNodeType::SEQ
+    }
+
+    pub fn is_map(&self) -> bool {
+        self.kind() == FileNodeType::MAP
+    }
+
+    /// Number of elements of a sequence or map, 1 for a scalar and 0 for `NONE`.
+    pub fn len(&self) -> usize {
+        unsafe { ffi::cv_file_node_size(self.pointer) }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Key of this node within its parent map.
+    pub fn name(&self) -> Result<String> {
+        let bytes = Bytes::default();
+        Result::from(unsafe { ffi::cv_file_node_name(self.pointer, bytes.pointer) })?;
+        Ok(bytes_to_string(&bytes))
+    }
+
+    /// Entry `name` of a map, a `NONE` node if there is none.
+    pub fn get(&self, name: &str) -> Result<FileNode<'a>> {
+        let name = c_string(name)?;
+        let pointer = Result::from(unsafe { ffi::cv_file_node_get(self.pointer, name.as_ptr()) })?;
+        Ok(FileNode::from_ptr(pointer))
+    }
+
+    /// Element `index` of a sequence, or entry `index` of a map in document order.
+    pub fn at(&self, index: usize) -> Result<FileNode<'a>> {
+        let pointer = Result::from(unsafe { ffi::cv_file_node_at(self.pointer, index) })?;
+        Ok(FileNode::from_ptr(pointer))
+    }
+
+    pub fn keys(&self) -> Result<Vec<String>> {
+        self.expect(FileNodeType::MAP)?;
+        (0..self.len()).map(|i| self.at(i)?.name()).collect()
+    }
+
+    pub fn read_i32(&self) -> Result<i32> {
+        self.expect(FileNodeType::INT)?;
+        Result::from(unsafe { ffi::cv_file_node_int(self.pointer) })
+    }
+
+    /// Read a `REAL` or an `INT` node.
+    pub fn read_f64(&self) -> Result<f64> {
+        if !self.is_int() {
+            self.expect(FileNodeType::REAL)?;
+        }
+        Result::from(unsafe { ffi::cv_file_node_real(self.pointer) })
+    }
+
+    pub fn read_string(&self) -> Result<String> {
+        self.expect(FileNodeType::STR)?;
+        let bytes = Bytes::default();
+        Result::from(unsafe { ffi::cv_file_node_string(self.pointer, bytes.pointer) })?;
+        Ok(bytes_to_string(&bytes))
+    }
+
+    /// Read an `opencv-matrix`, whose element type and channels must match `T` and `C`.
+    /// An `opencv-nd-matrix` is rejected; read it with [`FileNode::read_dyn_mat`].
+    pub fn read_mat<T: Depth, const C: usize>(&self) -> Result<Mat<T, C>> {
+        Mat::try_from(self.read_dyn_mat()?)
+    }
+
+    /// Read an `opencv-matrix` or `opencv-nd-matrix` of any type.
+    pub fn read_dyn_mat(&self) -> Result<DynMat> {
+        self.expect(FileNodeType::MAP)?;
+        let dst = DynMat::from(Mat::<u8, 1>::new()?);
+        Result::from(unsafe { ffi::cv_file_node_mat(self.pointer, dst.pointer) })?;
+        Ok(dst)
+    }
+}
+
+impl Drop for FileNode<'_> {
+    fn drop(&mut self) {
+        unsafe { ffi::cv_release_file_node(self.pointer) }
+    }
+}
+
+/// The base type such as `MAP`, without the `FLOW`, `EMPTY` and `NAMED` bits.
+impl fmt::Display for FileNodeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match *self & FileNodeType::TYPE_MASK {
+            FileNodeType::NONE => "NONE",
+            FileNodeType::INT => "INT",
+            FileNodeType::REAL => "REAL",
+            FileNodeType::STR => "STR",
+            FileNodeType::SEQ => "SEQ",
+            FileNodeType::MAP => "MAP",
+            other => return write!(f, "{}", other.bits()),
+        };
+        f.write_str(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DataTypes;
+
+    fn intrinsics() -> Mat<f64, 1> {
+        let data = [500., 0., 320., 0., 500., 240., 0., 0., 1.];
+        Mat::from_shape_vec(3, 3, &data).unwrap()
+    }
+
+    fn write_sample(fs: &mut FileStorage) {
+        fs.write_i32("count", 3).unwrap();
+        fs.write_f64("threshold", 0.25).unwrap();
+        fs.write_str("camera", "front").unwrap();
+        fs.write_mat("K", &intrinsics()).unwrap();
+        fs.start_write_struct("sizes", FileNodeType::SEQ, "")
+            .unwrap();
+        for size in [1, 2, 3] {
+            fs.write_i32("", size).unwrap();
+        }
+        fs.end_write_struct().unwrap();
+        fs.start_write_struct("roi", FileNodeType::MAP | FileNodeType::FLOW, "")
+            .unwrap();
+        fs.write_i32("x", 10).unwrap();
+        fs.write_i32("y", 20).unwrap();
+        fs.end_write_struct().unwrap();
+    }
+
+    fn check_sample(fs: &FileStorage) {
+        assert_eq!(fs.get("count").unwrap().read_i32().unwrap(), 3);
+        assert_eq!(fs.get("threshold").unwrap().read_f64().unwrap(), 0.25);
+        assert_eq!(fs.get("count").unwrap().read_f64().unwrap(), 3.);
+        assert_eq!(fs.get("camera").unwrap().read_string().unwrap(), "front");
+        let k = fs.get("K").unwrap().read_mat::<f64, 1>().unwrap();
+        assert_eq!(k.data(), intrinsics().data());
+
+        let sizes = fs.get("sizes").unwrap();
+        assert!(sizes.is_seq());
+        assert_eq!(sizes.len(), 3);
+        assert_eq!(sizes.at(2).unwrap().read_i32().unwrap(), 3);
+        assert!(sizes.at(3).is_err());
+
+        let roi = fs.get("roi").unwrap();
+        assert!(roi.is_map());
+        assert_eq!(roi.keys().unwrap(), ["x", "y"]);
+        assert_eq!(roi.get("y").unwrap().read_i32().unwrap(), 20);
+
+        let root = fs.root().unwrap();
+        assert_eq!(
+            root.keys().unwrap(),
+            ["count", "threshold", "camera", "K", "sizes", "roi"]
+        );
+        assert!(root.get("missing").unwrap().is_none());
+    }
+
+    #[test]
+    fn memory_test() {
+        for (format, prefix) in [
+            (FileStorageMode::FORMAT_YAML, "%YAML"),
+            (FileStorageMode::FORMAT_XML, "<?xml"),
+            (FileStorageMode::FORMAT_JSON, "{"),
+        ] {
+            let mut fs = FileStorage::new_in_memory(format).unwrap();
+            write_sample(&mut fs);
+            let content = fs.release_and_get_string().unwrap();
+            assert!(content.starts_with(prefix), "{}", content);
+            check_sample(&FileStorage::from_memory(&content).unwrap());
+        }
+    }
+
+    #[test]
+    fn file_test() {
+        let path = std::env::temp_dir().join("rxcv_persistence_test.json");
+        let path = path.to_str().unwrap();
+        let mut fs = FileStorage::open(path, FileStorageMode::WRITE).unwrap();
+        write_sample(&mut fs);
+        fs.release().unwrap();
+        check_sample(&FileStorage::open(path, FileStorageMode::READ).unwrap());
+        std::fs::remove_file(path).unwrap();
+        assert!(FileStorage::open(path, FileStorageMode::READ).is_err());
+    }
+
+    #[test]
+    fn type_mismatch_test() {
+        let content = "%YAML:1.0\n---\ncamera: front\nsize: 3\n";
+        let fs = FileStorage::from_memory(content).unwrap();
+        assert_eq!(
+            fs.get("camera").unwrap().read_i32(),
+            Err(Error::NodeTypeMismatch {
+                expected: FileNodeType::INT,
+                actual: FileNodeType::STR,
+            })
+        );
+        assert_eq!(
+            fs.get("camera").unwrap().keys().unwrap_err().to_string(),
+            "expected node type MAP, got STR"
+        );
+        assert!(fs.get("size").unwrap().read_string().is_err());
+        assert!(fs.get("size").unwrap().keys().is_err());
+
+        let mut fs = FileStorage::new_in_memory(FileStorageMode::FORMAT_YAML).unwrap();
+        fs.write_mat("K", &intrinsics()).unwrap();
+        let content = fs.release_and_get_string().unwrap();
+        let fs = FileStorage::from_memory(&content).unwrap();
+        assert_eq!(
+            fs.get("K").unwrap().read_mat::<f32, 1>().err(),
+            Some(Error::TypeMismatch {
+                expected: DataTypes::CV_32FC1.bits(),
+                actual: DataTypes::CV_64FC1.bits(),
+            })
+        );
+    }
+
+    #[test]
+    fn nd_matrix_test() {
+        let content = "%YAML:1.0\n---\nV: !!opencv-nd-matrix\n   sizes: [ 2, 2, 2 ]\n   dt: u\n   data: [ 0, 1, 2, 3, 4, 5, 6, 7 ]\n";
+        let fs = FileStorage::from_memory(content).unwrap();
+        let node = fs.get("V").unwrap();
+        assert!(matches!(
+            node.read_mat::<u8, 1>(),
+            Err(Error::InvalidArgument(_))
+        ));
+        let mat = crate::core::MatNd::<u8>::try_from(node.read_dyn_mat().unwrap()).unwrap();
+        assert_eq!(mat.shape(), vec![2, 2, 2]);
+        assert_eq!(mat.data(), &[0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+}
@@ -0,0 +1,195 @@
+//! Random fills. The `Mat` methods draw from OpenCV's default generator, which is
+//! per thread and seeded by [`set_rng_seed`]; [`Rng`] carries its own state instead.
+use super::{Depth, Mat, Scalar};
+use crate::result::Result;
+
+mod ffi {
+    use crate::{
+        core::{MatPointer, Scalar},
+        ffi::FFIResult,
+    };
+
+    #[link(name = "rxcv", kind = "static")]
+    extern "C" {
+        pub(super) fn cv_rng_fill(
+            state: *mut u64,
+            mat: *const MatPointer,
+            dist_type: i32,
+            a: Scalar,
+            b: Scalar,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_rand_shuffle(
+            state: *mut u64,
+            mat: *const MatPointer,
+            iter_factor: f64,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_set_rng_seed(seed: i32) -> FFIResult<i32>;
+    }
+}
+
+// cv::RNG::UNIFORM, cv::RNG::NORMAL
+const UNIFORM: i32 = 0;
+const NORMAL: i32 = 1;
+
+/// Seed the default generator of the calling thread.
+pub fn set_rng_seed(seed: i32) -> Result<()> {
+    Result::from(unsafe { ffi::cv_set_rng_seed(seed) })?;
+    Ok(())
+}
+
+fn fill<T: Depth, const C: usize>(
+    state: *mut u64,
+    mat: &mut Mat<T, C>,
+    dist_type: i32,
+    a: Scalar,
+    b: Scalar,
+) -> Result<()> {
+    Result::from(unsafe { ffi::cv_rng_fill(state, mat.pointer, dist_type, a, b) })?;
+    Ok(())
+}
+
+fn shuffle<T: Depth, const C: usize>(
+    state: *mut u64,
+    mat: &mut Mat<T, C>,
+    iter_factor: f64,
+) -> Result<()> {
+    Result::from(unsafe { ffi::cv_rand_shuffle(state, mat.pointer, iter_factor) })?;
+    Ok(())
+}
+
+/// `cv::RNG`, a multiply-with-carry generator whose whole state is one `u64`,
+/// so the same seed gives the same sequence on every run and thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// A zero seed is replaced by `0xffffffff`, as OpenCV does.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: if seed == 0 { 0xffff_ffff } else { seed },
+        }
+    }
+
+    pub fn state(&self) -> u64 {
+        self.state
+    }
+
+    /// Fill `mat` uniformly per channel in `low..high`.
+    pub fn randu<T: Depth, const C: usize>(
+        &mut self,
+        mat: &mut Mat<T, C>,
+        low: Scalar,
+        high: Scalar,
+    ) -> Result<()> {
+        fill(&mut self.state, mat, UNIFORM, low, high)
+    }
+
+    /// Fill `mat` with normally distributed values, saturated to the element type.
+    pub fn randn<T: Depth, const C: usize>(
+        &mut self,
+        mat: &mut Mat<T, C>,
+        mean: Scalar,
+        stddev: Scalar,
+    ) -> Result<()> {
+        fill(&mut self.state, mat, NORMAL, mean, stddev)
+    }
+
+    /// Swap `mat.total() * iter_factor` random pairs of elements.
+    pub fn rand_shuffle<T: Depth, const C: usize>(
+        &mut self,
+        mat: &mut Mat<T, C>,
+        iter_factor: f64,
+    ) -> Result<()> {
+        shuffle(&mut self.state, mat, iter_factor)
+    }
+}
+
+impl Default for Rng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<T: Depth, const C: usize> Mat<T, C> {
+    /// Fill uniformly per channel in `low..high` (`cv::randu`). The Mat must be allocated.
+    pub fn randu(&mut self, low: Scalar, high: Scalar) -> Result<()> {
+        fill(std::ptr::null_mut(), self, UNIFORM, low, high)
+    }
+
+    /// Fill with normally distributed values (`cv::randn`).
+    pub fn randn(&mut self, mean: Scalar, stddev: Scalar) -> Result<()> {
+        fill(std::ptr::null_mut(), self, NORMAL, mean, stddev)
+    }
+
+    /// Shuffle elements in place (`cv::randShuffle`).
+    pub fn rand_shuffle(&mut self, iter_factor: f64) -> Result<()> {
+        shuffle(std::ptr::null_mut(), self, iter_factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noise(seed: u64) -> Mat<u8, 3> {
+        let mut mat = Mat::zeros(16, 16).unwrap();
+        Rng::new(seed)
+            .randu(&mut mat, Scalar::all(10.), Scalar::all(20.))
+            .unwrap();
+        mat
+    }
+
+    #[test]
+    fn rng_test() {
+        assert_eq!(Rng::new(0), Rng::default());
+        assert_eq!(noise(42).data(), noise(42).data());
+        assert_ne!(noise(42).data(), noise(43).data());
+        assert!(noise(42).data().iter().all(|v| (10..20).contains(v)));
+
+        let mut rng = Rng::new(42);
+        let mut mat = Mat::<u8, 3>::zeros(16, 16).unwrap();
+        rng.randu(&mut mat, Scalar::all(10.), Scalar::all(20.))
+            .unwrap();
+        assert_ne!(rng.state(), 42);
+        rng.randu(&mut mat, Scalar::all(10.), Scalar::all(20.))
+            .unwrap();
+        assert_ne!(mat.data(), noise(42).data());
+    }
+
+    #[test]
+    fn randn_test() {
+        let mut mat = Mat::<f32, 1>::zeros(100, 100).unwrap();
+        Rng::new(1)
+            .randn(&mut mat, Scalar::all(5.), Scalar::all(2.))
+            .unwrap();
+        let (mean, stddev) = mat.mean_std_dev(None).unwrap();
+        assert!((mean[0] - 5.).abs() < 0.1);
+        assert!((stddev[0] - 2.).abs() < 0.1);
+    }
+
+    #[test]
+    fn rand_shuffle_test() {
+        let data = (0..100).collect::<Vec<i32>>();
+        let mut mat = Mat::<i32, 1>::from_shape_vec(10, 10, &data).unwrap();
+        Rng::new(7).rand_shuffle(&mut mat, 1.).unwrap();
+        assert_ne!(mat.data(), &data[..]);
+        let mut sorted = mat.data().to_vec();
+        sorted.sort_unstable();
+        assert_eq!(sorted, data);
+    }
+
+    #[test]
+    fn set_rng_seed_test() {
+        let mut a = Mat::<f64, 2>::zeros(8, 8).unwrap();
+        let mut b = Mat::<f64, 2>::zeros(8, 8).unwrap();
+        set_rng_seed(7).unwrap();
+        a.randn(Scalar::all(0.), Scalar::all(1.)).unwrap();
+        set_rng_seed(7).unwrap();
+        b.randn(Scalar::all(0.), Scalar::all(1.)).unwrap();
+        assert_eq!(a.data(), b.data());
+        a.randu(Scalar::all(-1.), Scalar::all(1.)).unwrap();
+        assert!(a.data().iter().all(|v| (-1. ..1.).contains(v)));
+    }
+}
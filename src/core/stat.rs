@@ -0,0 +1,186 @@
+//! Whole-image reductions. Every `mask` is an optional `Mat<u8, 1>` of the
+//! same size; only elements where it is non-zero take part.
+use super::{arithm::mask_pointer, Depth, Mat, NormTypes, Point, Scalar};
+use crate::result::Result;
+
+mod ffi {
+    use crate::{
+        core::{MatPointer, Point, Scalar},
+        ffi::FFIResult,
+    };
+
+    #[link(name = "rxcv", kind = "static")]
+    extern "C" {
+        pub(super) fn cv_sum(src: *const MatPointer) -> FFIResult<Scalar>;
+        pub(super) fn cv_mean(src: *const MatPointer, mask: *const MatPointer)
+            -> FFIResult<Scalar>;
+        pub(super) fn cv_mean_std_dev(
+            src: *const MatPointer,
+            mean: *mut Scalar,
+            stddev: *mut Scalar,
+            mask: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_min_max_loc(
+            src: *const MatPointer,
+            min_val: *mut f64,
+            max_val: *mut f64,
+            min_loc: *mut Point,
+            max_loc: *mut Point,
+            mask: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_count_non_zero(
+            src: *const MatPointer,
+            mask: *const MatPointer,
+        ) -> FFIResult<i32>;
+        pub(super) fn cv_norm(
+            src: *const MatPointer,
+            norm_type: i32,
+            mask: *const MatPointer,
+        ) -> FFIResult<f64>;
+        pub(super) fn cv_norm_diff(
+            src1: *const MatPointer,
+            src2: *const MatPointer,
+            norm_type: i32,
+            mask: *const MatPointer,
+        ) -> FFIResult<f64>;
+    }
+}
+
+fn channels<const C: usize>(s: Scalar) -> [f64; C] {
+    std::array::from_fn(|i| s.val[i])
+}
+
+impl<T: Depth, const C: usize> Mat<T, C> {
+    /// Per-channel sum. OpenCV supports up to four channels.
+    pub fn sum(&self) -> Result<[f64; C]> {
+        let s = Result::from(unsafe { ffi::cv_sum(self.pointer) })?;
+        Ok(channels(s))
+    }
+
+    /// Per-channel mean.
+    pub fn mean(&self, mask: Option<&Mat<u8, 1>>) -> Result<[f64; C]> {
+        let s = Result::from(unsafe { ffi::cv_mean(self.pointer, mask_pointer(mask)) })?;
+        Ok(channels(s))
+    }
+
+    /// Per-channel `(mean, standard deviation)`.
+    pub fn mean_std_dev(&self, mask: Option<&Mat<u8, 1>>) -> Result<([f64; C], [f64; C])> {
+        let mut mean = Scalar::default();
+        let mut stddev = Scalar::default();
+        Result::from(unsafe {
+            ffi::cv_mean_std_dev(self.pointer, &mut mean, &mut stddev, mask_pointer(mask))
+        })?;
+        Ok((channels(mean), channels(stddev)))
+    }
+
+    /// `cv::norm` of `self`.
+    pub fn norm(&self, norm_type: NormTypes, mask: Option<&Mat<u8, 1>>) -> Result<f64> {
+        Result::from(unsafe { ffi::cv_norm(self.pointer, norm_type.bits(), mask_pointer(mask)) })
+    }
+
+    /// `cv::norm` of `self - other`, or relative to `other` with `NORM_RELATIVE`.
+    pub fn norm_diff(
+        &self,
+        other: &Self,
+        norm_type: NormTypes,
+        mask: Option<&Mat<u8, 1>>,
+    ) -> Result<f64> {
+        Result::from(unsafe {
+            ffi::cv_norm_diff(
+                self.pointer,
+                other.pointer,
+                norm_type.bits(),
+                mask_pointer(mask),
+            )
+        })
+    }
+}
+
+impl<T: Depth> Mat<T, 1> {
+    /// `(min, max, min location, max location)`. Locations are `(-1, -1)`
+    /// when the mask selects nothing.
+    pub fn min_max_loc(&self, mask: Option<&Mat<u8, 1>>) -> Result<(f64, f64, Point, Point)> {
+        let mut min_val = 0.;
+        let mut max_val = 0.;
+        let mut min_loc = Point::default();
+        let mut max_loc = Point::default();
+        Result::from(unsafe {
+            ffi::cv_min_max_loc(
+                self.pointer,
+                &mut min_val,
+                &mut max_val,
+                &mut min_loc,
+                &mut max_loc,
+                mask_pointer(mask),
+            )
+        })?;
+        Ok((min_val, max_val, min_loc, max_loc))
+    }
+
+    pub fn count_non_zero(&self, mask: Option<&Mat<u8, 1>>) -> Result<usize> {
+        let count =
+            Result::from(unsafe { ffi::cv_count_non_zero(self.pointer, mask_pointer(mask)) })?;
+        Ok(count as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mat(data: &[f32]) -> Mat<f32, 1> {
+        Mat::from_shape_vec(2, data.len() / 2, data).unwrap()
+    }
+
+    #[test]
+    fn sum_mean_test() {
+        let src = Mat::mock_7x6_square_5x4();
+        assert_eq!(src.sum().unwrap(), [5100.; 3]);
+        let mean = src.mean(None).unwrap();
+        assert!((mean[0] - 5100. / 42.).abs() < 1e-9);
+        let mask = src.cvt_color_bgr2gray().unwrap();
+        assert_eq!(src.mean(Some(&mask)).unwrap(), [255.; 3]);
+    }
+
+    #[test]
+    fn mean_std_dev_test() {
+        let src = mat(&[1., 3., 1., 3.]);
+        let (mean, stddev) = src.mean_std_dev(None).unwrap();
+        assert_eq!(mean, [2.]);
+        assert_eq!(stddev, [1.]);
+    }
+
+    #[test]
+    fn min_max_loc_test() {
+        let src = mat(&[4., -2., 7., 0., 9., 1.]);
+        let (min, max, min_loc, max_loc) = src.min_max_loc(None).unwrap();
+        assert_eq!((min, max), (-2., 9.));
+        assert_eq!(min_loc, Point { x: 1, y: 0 });
+        assert_eq!(max_loc, Point { x: 1, y: 1 });
+
+        let mask = Mat::<u8, 1>::from_shape_vec(2, 3, &[1, 0, 1, 1, 0, 1]).unwrap();
+        let (min, max, _, max_loc) = src.min_max_loc(Some(&mask)).unwrap();
+        assert_eq!((min, max), (0., 7.));
+        assert_eq!(max_loc, Point { x: 2, y: 0 });
+    }
+
+    #[test]
+    fn count_non_zero_test() {
+        let src = mat(&[0., 1., 2., 0.]);
+        assert_eq!(src.count_non_zero(None).unwrap(), 2);
+        let mask = Mat::<u8, 1>::from_shape_vec(2, 2, &[0, 0, 1, 1]).unwrap();
+        assert_eq!(src.count_non_zero(Some(&mask)).unwrap(), 1);
+    }
+
+    #[test]
+    fn norm_test() {
+        let a = mat(&[3., 0., 0., -4.]);
+        let b = Mat::<f32, 1>::zeros(2, 2).unwrap();
+        assert_eq!(a.norm(NormTypes::NORM_L2, None).unwrap(), 5.);
+        assert_eq!(a.norm(NormTypes::NORM_L1, None).unwrap(), 7.);
+        assert_eq!(a.norm(NormTypes::NORM_INF, None).unwrap(), 4.);
+        assert_eq!(a.norm_diff(&b, NormTypes::NORM_L2SQR, None).unwrap(), 25.);
+        let mask = Mat::<u8, 1>::from_shape_vec(2, 2, &[1, 0, 0, 0]).unwrap();
+        assert_eq!(a.norm(NormTypes::NORM_L2, Some(&mask)).unwrap(), 3.);
+    }
+}
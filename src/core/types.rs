@@ -0,0 +1,591 @@
+//! Basic value types mirroring `opencv2/core/types.hpp`.
+use std::ops::{Add, AddAssign, BitAnd, BitOr, Div, Mul, Neg, Sub, SubAssign};
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PointBase<T> {
+    pub x: T,
+    pub y: T,
+}
+
+pub type Point2i = PointBase<i32>;
+pub type Point2f = PointBase<f32>;
+pub type Point2d = PointBase<f64>;
+pub type Point = Point2i;
+
+impl Point {
+    /// `(-1, -1)`, which OpenCV reads as "the kernel center" when passed as an anchor.
+    pub const ANCHOR_CENTER: Self = Self::new(-1, -1);
+}
+
+impl<T> PointBase<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> PointBase<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point3_<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+pub type Point3i = Point3_<i32>;
+pub type Point3f = Point3_<f32>;
+pub type Point3d = Point3_<f64>;
+
+impl<T> Point3_<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> Point3_<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(&self, other: &Self) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+}
+
+macro_rules! impl_point_ops {
+    ($name:ident, $($field:ident),+) => {
+        impl<T: Add<Output = T>> Add for $name<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $name<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $name<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $name<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                Self { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl<T: Copy + Div<Output = T>> Div<T> for $name<T> {
+            type Output = Self;
+
+            fn div(self, rhs: T) -> Self {
+                Self { $($field: self.$field / rhs),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $name<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $name<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field -= rhs.$field;)+
+            }
+        }
+    };
+}
+
+impl_point_ops!(PointBase, x, y);
+impl_point_ops!(Point3_, x, y, z);
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SizeT<T> {
+    pub width: T,
+    pub height: T,
+}
+
+pub type Size2i = SizeT<i32>;
+pub type Size2f = SizeT<f32>;
+pub type Size2d = SizeT<f64>;
+pub type Size = Size2i;
+
+impl<T> SizeT<T> {
+    pub const fn new(width: T, height: T) -> Self {
+        Self { width, height }
+    }
+}
+
+impl<T> SizeT<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    pub fn area(&self) -> T {
+        self.width * self.height
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect_<T> {
+    pub x: T,
+    pub y: T,
+    pub width: T,
+    pub height: T,
+}
+
+pub type Rect2i = Rect_<i32>;
+pub type Rect2f = Rect_<f32>;
+pub type Rect2d = Rect_<f64>;
+pub type Rect = Rect2i;
+
+impl<T> Rect_<T> {
+    pub const fn new(x: T, y: T, width: T, height: T) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Rect_<T>
+where
+    T: Copy + Default + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Rectangle spanning the two corners `tl` (inclusive) and `br` (exclusive).
+    pub fn from_points(tl: PointBase<T>, br: PointBase<T>) -> Self {
+        let (x0, x1) = min_max(tl.x, br.x);
+        let (y0, y1) = min_max(tl.y, br.y);
+        Self::new(x0, y0, x1 - x0, y1 - y0)
+    }
+
+    /// Top-left corner.
+    pub fn tl(&self) -> PointBase<T> {
+        PointBase::new(self.x, self.y)
+    }
+
+    /// Bottom-right corner, exclusive.
+    pub fn br(&self) -> PointBase<T> {
+        PointBase::new(self.x + self.width, self.y + self.height)
+    }
+
+    pub fn size(&self) -> SizeT<T> {
+        SizeT::new(self.width, self.height)
+    }
+
+    pub fn area(&self) -> T {
+        self.width * self.height
+    }
+
+    pub fn empty(&self) -> bool {
+        !(self.width > T::default() && self.height > T::default())
+    }
+
+    /// Whether `point` lies inside, with the right and bottom edges excluded as in OpenCV.
+    pub fn contains(&self, point: PointBase<T>) -> bool {
+        self.x <= point.x
+            && point.x < self.x + self.width
+            && self.y <= point.y
+            && point.y < self.y + self.height
+    }
+}
+
+fn min_max<T: PartialOrd>(a: T, b: T) -> (T, T) {
+    if b < a {
+        (b, a)
+    } else {
+        (a, b)
+    }
+}
+
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if a < b {
+        b
+    } else {
+        a
+    }
+}
+
+fn min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+/// Intersection, empty when the rectangles do not overlap.
+impl<T> BitAnd for Rect_<T>
+where
+    T: Copy + Default + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        let x = max(self.x, rhs.x);
+        let y = max(self.y, rhs.y);
+        let right = min(self.x + self.width, rhs.x + rhs.width);
+        let bottom = min(self.y + self.height, rhs.y + rhs.height);
+        if right <= x || bottom <= y {
+            Self::default()
+        } else {
+            Self::new(x, y, right - x, bottom - y)
+        }
+    }
+}
+
+/// Smallest rectangle containing both. An empty operand is ignored.
+impl<T> BitOr for Rect_<T>
+where
+    T: Copy + Default + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        if self.empty() {
+            return rhs;
+        }
+        if rhs.empty() {
+            return self;
+        }
+        let x = min(self.x, rhs.x);
+        let y = min(self.y, rhs.y);
+        let right = max(self.x + self.width, rhs.x + rhs.width);
+        let bottom = max(self.y + self.height, rhs.y + rhs.height);
+        Self::new(x, y, right - x, bottom - y)
+    }
+}
+
+/// Translate by a point offset.
+impl<T: Add<Output = T>> Add<PointBase<T>> for Rect_<T> {
+    type Output = Self;
+
+    fn add(self, rhs: PointBase<T>) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.width, self.height)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<PointBase<T>> for Rect_<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: PointBase<T>) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.width, self.height)
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Scalar_<T> {
+    pub val: [T; 4],
+}
+
+pub type Scalar = Scalar_<f64>;
+
+impl<T: Copy + Default> Scalar_<T> {
+    pub const fn new(v0: T, v1: T, v2: T, v3: T) -> Self {
+        Self {
+            val: [v0, v1, v2, v3],
+        }
+    }
+
+    /// Every component set to `v`.
+    pub fn all(v: T) -> Self {
+        Self { val: [v; 4] }
+    }
+}
+
+impl<T> std::ops::Index<usize> for Scalar_<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.val[index]
+    }
+}
+
+impl<T> std::ops::IndexMut<usize> for Scalar_<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.val[index]
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct RotatedRect {
+    pub center: Point2f,
+    pub size: Size2f,
+    /// Clockwise rotation in degrees.
+    pub angle: f32,
+}
+
+impl RotatedRect {
+    pub const fn new(center: Point2f, size: Size2f, angle: f32) -> Self {
+        Self {
+            center,
+            size,
+            angle,
+        }
+    }
+
+    /// Corners in the order bottom-left, top-left, top-right, bottom-right, as `cv::RotatedRect::points`.
+    pub fn points(&self) -> [Point2f; 4] {
+        let angle = (self.angle as f64).to_radians();
+        let b = angle.cos() as f32 * 0.5;
+        let a = angle.sin() as f32 * 0.5;
+        let (w, h) = (self.size.width, self.size.height);
+        let p0 = Point2f::new(self.center.x - a * h - b * w, self.center.y + b * h - a * w);
+        let p1 = Point2f::new(self.center.x + a * h - b * w, self.center.y - b * h - a * w);
+        let p2 = Point2f::new(2. * self.center.x - p0.x, 2. * self.center.y - p0.y);
+        let p3 = Point2f::new(2. * self.center.x - p1.x, 2. * self.center.y - p1.y);
+        [p0, p1, p2, p3]
+    }
+
+    /// Smallest integer rectangle containing every corner.
+    pub fn bounding_rect(&self) -> Rect {
+        let points = self.points();
+        let fold = |f: fn(f32, f32) -> f32, get: fn(&Point2f) -> f32| {
+            points.iter().map(get).reduce(f).unwrap()
+        };
+        let left = fold(f32::min, |p| p.x).floor() as i32;
+        let top = fold(f32::min, |p| p.y).floor() as i32;
+        let right = fold(f32::max, |p| p.x).ceil() as i32;
+        let bottom = fold(f32::max, |p| p.y).ceil() as i32;
+        Rect::new(left, top, right - left + 1, bottom - top + 1)
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Range {
+    pub start: i32,
+    pub end: i32,
+}
+
+impl Range {
+    pub const fn new(start: i32, end: i32) -> Self {
+        Self { start, end }
+    }
+
+    /// The whole sequence, as `cv::Range::all()`.
+    pub const fn all() -> Self {
+        Self {
+            start: i32::MIN,
+            end: i32::MAX,
+        }
+    }
+
+    pub fn size(&self) -> i32 {
+        self.end - self.start
+    }
+
+    pub fn empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+impl From<std::ops::Range<i32>> for Range {
+    fn from(range: std::ops::Range<i32>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl From<Range> for std::ops::Range<i32> {
+    fn from(range: Range) -> Self {
+        range.start..range.end
+    }
+}
+
+impl From<(i32, i32)> for Range {
+    fn from((start, end): (i32, i32)) -> Self {
+        Self::new(start, end)
+    }
+}
+
+impl From<Range> for (i32, i32) {
+    fn from(range: Range) -> Self {
+        (range.start, range.end)
+    }
+}
+
+macro_rules! impl_conversions {
+    ($name:ident, $n:tt, $($field:ident),+) => {
+        impl<T> From<($(impl_conversions!(@t $field),)+)> for $name<T> {
+            fn from(($($field,)+): ($(impl_conversions!(@t $field),)+)) -> Self {
+                Self { $($field),+ }
+            }
+        }
+
+        impl<T> From<$name<T>> for ($(impl_conversions!(@t $field),)+) {
+            fn from(value: $name<T>) -> Self {
+                ($(value.$field,)+)
+            }
+        }
+
+        impl<T> From<[T; $n]> for $name<T> {
+            fn from([$($field),+]: [T; $n]) -> Self {
+                Self { $($field),+ }
+            }
+        }
+
+        impl<T> From<$name<T>> for [T; $n] {
+            fn from(value: $name<T>) -> Self {
+                [$(value.$field),+]
+            }
+        }
+    };
+    (@t $field:ident) => {
+        T
+    };
+}
+
+impl_conversions!(PointBase, 2, x, y);
+impl_conversions!(Point3_, 3, x, y, z);
+impl_conversions!(SizeT, 2, width, height);
+impl_conversions!(Rect_, 4, x, y, width, height);
+
+impl<T> From<[T; 4]> for Scalar_<T> {
+    fn from(val: [T; 4]) -> Self {
+        Self { val }
+    }
+}
+
+impl<T> From<Scalar_<T>> for [T; 4] {
+    fn from(value: Scalar_<T>) -> Self {
+        value.val
+    }
+}
+
+impl<T> From<(T, T, T, T)> for Scalar_<T> {
+    fn from((v0, v1, v2, v3): (T, T, T, T)) -> Self {
+        Self {
+            val: [v0, v1, v2, v3],
+        }
+    }
+}
+
+impl From<f64> for Scalar {
+    fn from(v: f64) -> Self {
+        Self::new(v, 0., 0., 0.)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_ops_test() {
+        let a = Point::new(1, 2);
+        let b = Point::new(3, -4);
+        assert_eq!(a + b, Point::new(4, -2));
+        assert_eq!(a - b, Point::new(-2, 6));
+        assert_eq!(a * 3, Point::new(3, 6));
+        assert_eq!(-a, Point::new(-1, -2));
+        assert_eq!(a.dot(&b), -5);
+        let mut c = a;
+        c += b;
+        assert_eq!(c, Point::new(4, -2));
+        assert_eq!(Point2d::new(1., 2.) / 2., Point2d::new(0.5, 1.));
+        assert_eq!(Point::default(), Point::new(0, 0));
+        assert_eq!(Point2f::default(), Point2f::new(0., 0.));
+        assert_eq!(Point::ANCHOR_CENTER, Point::new(-1, -1));
+    }
+
+    #[test]
+    fn point3_ops_test() {
+        let a = Point3i::new(1, 0, 0);
+        let b = Point3i::new(0, 1, 0);
+        assert_eq!(a.cross(&b), Point3i::new(0, 0, 1));
+        assert_eq!(a + b, Point3i::new(1, 1, 0));
+        assert_eq!(Point3f::new(1., 2., 3.) * 2., Point3f::new(2., 4., 6.));
+    }
+
+    #[test]
+    fn rect_test() {
+        let a = Rect::new(0, 0, 4, 3);
+        let b = Rect::new(2, 1, 4, 4);
+        assert_eq!(a.area(), 12);
+        assert_eq!(a.br(), Point::new(4, 3));
+        assert_eq!(a & b, Rect::new(2, 1, 2, 2));
+        assert_eq!(a | b, Rect::new(0, 0, 6, 5));
+        assert_eq!(a & Rect::new(10, 10, 1, 1), Rect::default());
+        assert!((a & Rect::new(10, 10, 1, 1)).empty());
+        assert!(a.contains(Point::new(3, 2)));
+        assert!(!a.contains(Point::new(4, 2)));
+        assert_eq!(
+            Rect::from_points(Point::new(4, 3), Point::new(1, 1)),
+            Rect::new(1, 1, 3, 2)
+        );
+        assert_eq!(a + Point::new(1, 1), Rect::new(1, 1, 4, 3));
+    }
+
+    #[test]
+    fn conversion_test() {
+        let p: Point = (1, 2).into();
+        assert_eq!(p, Point::new(1, 2));
+        assert_eq!(<[i32; 2]>::from(p), [1, 2]);
+        let p: Point3d = [1., 2., 3.].into();
+        assert_eq!(<(f64, f64, f64)>::from(p), (1., 2., 3.));
+        let s: Size2f = (2., 3.).into();
+        assert_eq!(s.area(), 6.);
+        let r: Rect = [1, 2, 3, 4].into();
+        assert_eq!(<(i32, i32, i32, i32)>::from(r), (1, 2, 3, 4));
+        let s: Scalar = (1., 2., 3., 4.).into();
+        assert_eq!(s[2], 3.);
+        assert_eq!(Scalar::from(5.), Scalar::new(5., 0., 0., 0.));
+        let range: Range = (2..5).into();
+        assert_eq!(range.size(), 3);
+        assert_eq!(std::ops::Range::from(range), 2..5);
+    }
+
+    #[test]
+    fn rotated_rect_test() {
+        let rect = RotatedRect::new(Point2f::new(5., 5.), Size2f::new(4., 2.), 0.);
+        let points = rect.points();
+        assert_eq!(points[0], Point2f::new(3., 6.));
+        assert_eq!(points[1], Point2f::new(3., 4.));
+        assert_eq!(points[2], Point2f::new(7., 4.));
+        assert_eq!(points[3], Point2f::new(7., 6.));
+        assert_eq!(rect.bounding_rect(), Rect::new(3, 4, 5, 3));
+    }
+
+    #[test]
+    fn layout_test() {
+        assert_eq!(std::mem::size_of::<Point>(), 8);
+        assert_eq!(std::mem::size_of::<Point3d>(), 24);
+        assert_eq!(std::mem::size_of::<Rect>(), 16);
+        assert_eq!(std::mem::size_of::<Scalar>(), 32);
+        assert_eq!(std::mem::size_of::<RotatedRect>(), 20);
+        assert_eq!(std::mem::size_of::<Range>(), 8);
+    }
+}
@@ -0,0 +1,302 @@
+use super::{mat::check_shape_len, Depth, Mat, MatPointer, Rect};
+use crate::result::Result;
+use std::{marker::PhantomData, ops::Deref};
+
+mod ffi {
+    use super::MatPointer;
+    use crate::{core::Rect, ffi::FFIResult};
+
+    #[link(name = "rxcv", kind = "static")]
+    extern "C" {
+        pub(super) fn cv_mat_roi(
+            pointer: *const MatPointer,
+            rect: Rect,
+        ) -> FFIResult<*const MatPointer>;
+        pub(super) fn cv_mat_row_range(
+            pointer: *const MatPointer,
+            start: i32,
+            end: i32,
+        ) -> FFIResult<*const MatPointer>;
+        pub(super) fn cv_mat_col_range(
+            pointer: *const MatPointer,
+            start: i32,
+            end: i32,
+        ) -> FFIResult<*const MatPointer>;
+        pub(super) fn cv_mat_from_data(
+            rows: i32,
+            cols: i32,
+            r#type: i32,
+            data: *mut std::ffi::c_void,
+            step: usize,
+        ) -> FFIResult<*const MatPointer>;
+    }
+}
+
+/// Header over a buffer owned by Rust, with `row_stride` elements between rows.
+pub(super) fn from_data<T: Depth, const C: usize>(
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    data: *mut T,
+) -> Result<*const MatPointer> {
+    Result::from(unsafe {
+        ffi::cv_mat_from_data(
+            rows as i32,
+            cols as i32,
+            Mat::<T, C>::TYPE,
+            data as *mut std::ffi::c_void,
+            row_stride * std::mem::size_of::<T>(),
+        )
+    })
+}
+
+/// Read-only Mat borrowing a buffer it does not own.
+///
+/// Derefs to [`Mat`], so every method and imgproc trait of `Mat<T, C>` is available.
+pub struct MatRef<'a, T, const C: usize> {
+    inner: Mat<T, C>,
+    _marker: PhantomData<&'a T>,
+}
+
+/// Writable Mat borrowing a buffer it does not own.
+pub struct MatMut<'a, T, const C: usize> {
+    inner: Mat<T, C>,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<T, const C: usize> MatRef<'_, T, C> {
+    pub(crate) fn from_ptr(pointer: *const MatPointer) -> Self {
+        Self {
+            inner: Mat::from_ptr(pointer),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: Depth, const C: usize> MatRef<'a, T, C> {
+    /// Borrow `data`, laid out row by row with `C` interleaved channels, without copying.
+    pub fn from_slice(rows: usize, cols: usize, data: &'a [T]) -> Result<Self> {
+        check_shape_len::<C>(rows, cols, data.len())?;
+        let pointer = from_data::<T, C>(rows, cols, cols * C, data.as_ptr() as *mut T)?;
+        Ok(Self::from_ptr(pointer))
+    }
+}
+
+impl<T, const C: usize> Deref for MatRef<'_, T, C> {
+    type Target = Mat<T, C>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T, const C: usize> MatMut<'_, T, C> {
+    pub(crate) fn from_ptr(pointer: *const MatPointer) -> Self {
+        Self {
+            inner: Mat::from_ptr(pointer),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn data_mut(&mut self) -> &mut [T] {
+        self.inner.data_mut()
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        self.inner.row_mut(row)
+    }
+
+    pub fn at_mut(&mut self, row: usize, col: usize) -> &mut [T; C] {
+        self.inner.at_mut(row, col)
+    }
+}
+
+impl<'a, T: Depth, const C: usize> MatMut<'a, T, C> {
+    pub fn from_slice_mut(rows: usize, cols: usize, data: &'a mut [T]) -> Result<Self> {
+        check_shape_len::<C>(rows, cols, data.len())?;
+        let pointer = from_data::<T, C>(rows, cols, cols * C, data.as_mut_ptr())?;
+        Ok(Self::from_ptr(pointer))
+    }
+}
+
+// MatMut deliberately has no DerefMut: `&mut Mat` would allow swapping the
+// borrowed header out of the view and past the lifetime of its buffer.
+impl<T, const C: usize> Deref for MatMut<'_, T, C> {
+    type Target = Mat<T, C>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T, const C: usize> Mat<T, C> {
+    /// View of the region `rect`, sharing the buffer of `self` (`cv::Mat(const Mat&, Rect)`).
+    pub fn roi(&self, rect: Rect) -> Result<MatRef<'_, T, C>> {
+        let pointer = Result::from(unsafe { ffi::cv_mat_roi(self.pointer, rect) })?;
+        Ok(MatRef::from_ptr(pointer))
+    }
+
+    pub fn roi_mut(&mut self, rect: Rect) -> Result<MatMut<'_, T, C>> {
+        let pointer = Result::from(unsafe { ffi::cv_mat_roi(self.pointer, rect) })?;
+        Ok(MatMut::from_ptr(pointer))
+    }
+
+    /// View of rows `start..end`.
+    pub fn row_range(&self, start: usize, end: usize) -> Result<MatRef<'_, T, C>> {
+        let pointer =
+            Result::from(unsafe { ffi::cv_mat_row_range(self.pointer, start as i32, end as i32) })?;
+        Ok(MatRef::from_ptr(pointer))
+    }
+
+    pub fn row_range_mut(&mut self, start: usize, end: usize) -> Result<MatMut<'_, T, C>> {
+        let pointer =
+            Result::from(unsafe { ffi::cv_mat_row_range(self.pointer, start as i32, end as i32) })?;
+        Ok(MatMut::from_ptr(pointer))
+    }
+
+    /// View of columns `start..end`.
+    pub fn col_range(&self, start: usize, end: usize) -> Result<MatRef<'_, T, C>> {
+        let pointer =
+            Result::from(unsafe { ffi::cv_mat_col_range(self.pointer, start as i32, end as i32) })?;
+        Ok(MatRef::from_ptr(pointer))
+    }
+
+    pub fn col_range_mut(&mut self, start: usize, end: usize) -> Result<MatMut<'_, T, C>> {
+        let pointer =
+            Result::from(unsafe { ffi::cv_mat_col_range(self.pointer, start as i32, end as i32) })?;
+        Ok(MatMut::from_ptr(pointer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        imgproc::{GaussianBlur, ThresholdTypes},
+        result::Error,
+        BorderTypes, Size,
+    };
+
+    #[test]
+    fn roi_test() {
+        let src = Mat::mock_7x6_square_5x4();
+        let rect = Rect {
+            x: 1,
+            y: 2,
+            width: 3,
+            height: 2,
+        };
+        let roi = src.roi(rect).unwrap();
+        assert_eq!(roi.cols(), 3);
+        assert_eq!(roi.rows(), 2);
+        assert_eq!(roi.channels(), 3);
+        assert!(!roi.is_continuous());
+        assert_eq!(roi.at(0, 0), src.at(2, 1));
+        assert_eq!(roi.at(1, 2), src.at(3, 3));
+        assert_eq!(roi.row(1), &src.row(3)[3..12]);
+    }
+
+    #[test]
+    fn roi_out_of_range_test() {
+        let src = Mat::mock_7x6_white();
+        let rect = Rect {
+            x: 5,
+            y: 5,
+            width: 3,
+            height: 3,
+        };
+        assert!(src.roi(rect).is_err());
+    }
+
+    #[test]
+    fn roi_mut_test() {
+        let mut src = Mat::mock_7x6_white();
+        let rect = Rect {
+            x: 2,
+            y: 1,
+            width: 2,
+            height: 3,
+        };
+        {
+            let mut roi = src.roi_mut(rect).unwrap();
+            for row in 0..3 {
+                roi.row_mut(row).iter_mut().for_each(|v| *v = 0);
+            }
+        }
+        assert_eq!(src.at(1, 2), &[0, 0, 0]);
+        assert_eq!(src.at(3, 3), &[0, 0, 0]);
+        assert_eq!(src.at(1, 1), &[255, 255, 255]);
+        assert_eq!(src.at(4, 2), &[255, 255, 255]);
+        assert_eq!(src.at(1, 4), &[255, 255, 255]);
+    }
+
+    #[test]
+    fn range_test() {
+        let src = Mat::mock_7x6_white();
+        let rows = src.row_range(1, 4).unwrap();
+        assert_eq!(rows.rows(), 3);
+        assert_eq!(rows.cols(), 7);
+        assert!(rows.is_continuous());
+        assert_eq!(rows.data().len(), 3 * 7 * 3);
+        let cols = src.col_range(2, 5).unwrap();
+        assert_eq!(cols.rows(), 6);
+        assert_eq!(cols.cols(), 3);
+        assert!(!cols.is_continuous());
+    }
+
+    #[test]
+    fn roi_filter_test() {
+        let src = Mat::mock_7x6_square_5x4();
+        let rect = Rect {
+            x: 1,
+            y: 1,
+            width: 4,
+            height: 3,
+        };
+        let roi = src.roi(rect).unwrap();
+        let ksize = Size {
+            width: 3,
+            height: 3,
+        };
+        let blurred = roi
+            .gaussian_blur(ksize, 1., 0., BorderTypes::BORDER_DEFAULT)
+            .unwrap();
+        assert_eq!(blurred.cols(), 4);
+        assert_eq!(blurred.rows(), 3);
+        let gray = src.cvt_color_bgr2gray().unwrap();
+        let roi = gray.roi(rect).unwrap();
+        let (_, dst) = roi
+            .threshold(0, 255, ThresholdTypes::THRESH_BINARY)
+            .unwrap();
+        assert_eq!(dst.cols(), 4);
+        assert_eq!(dst.rows(), 3);
+    }
+
+    #[test]
+    fn from_slice_test() {
+        let data = (0..4 * 6).map(|v| v as u8 * 10).collect::<Vec<_>>();
+        let view = MatRef::<u8, 2>::from_slice(4, 3, &data).unwrap();
+        assert_eq!(view.at(1, 2), &[100, 110]);
+        assert_eq!(view.data().as_ptr(), data.as_ptr());
+        let (_, dst) = view
+            .extract_channel(0)
+            .unwrap()
+            .threshold(100, 255, ThresholdTypes::THRESH_BINARY)
+            .unwrap();
+        assert_eq!(dst.at(3, 2), &[255]);
+        assert!(matches!(
+            MatRef::<u8, 2>::from_slice(4, 4, &data),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn from_slice_mut_test() {
+        let mut data = vec![0f32; 2 * 3];
+        {
+            let mut view = MatMut::<f32, 1>::from_slice_mut(2, 3, &mut data).unwrap();
+            *view.at_mut(1, 1) = [5.];
+        }
+        assert_eq!(data, [0., 0., 0., 0., 5., 0.]);
+    }
+}
@@ -1,5 +1,37 @@
+use std::ffi::{c_char, CStr};
+
 #[repr(C)]
 pub struct FFIResult<T> {
     pub ok: T,
-    pub error: *const std::ffi::c_char,
+    pub error: *const FFIError,
+}
+
+#[repr(C)]
+pub struct FFIError {
+    pub kind: i32,
+    pub code: i32,
+    pub message: *const c_char,
+    pub function: *const c_char,
+    pub file: *const c_char,
+    pub line: i32,
+}
+
+pub const FFI_ERROR_OPENCV: i32 = 0;
+pub const FFI_ERROR_STD: i32 = 1;
+
+#[link(name = "rxcv", kind = "static")]
+extern "C" {
+    pub fn cv_release_error(error: *const FFIError);
+}
+
+/// Copy a C string owned by an `FFIError` into a `String`.
+///
+/// # Safety
+/// `s` must be null or point to a valid nul-terminated string.
+pub unsafe fn to_string(s: *const c_char) -> String {
+    if s.is_null() {
+        String::new()
+    } else {
+        CStr::from_ptr(s).to_string_lossy().into_owned()
+    }
 }
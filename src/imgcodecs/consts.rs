@@ -17,3 +17,19 @@ bitflags! {
        const IMREAD_IGNORE_ORIENTATION = 128;
     }
 }
+
+bitflags! {
+    pub struct ImwriteFlags: i32 {
+       const IMWRITE_JPEG_QUALITY = 1;
+       const IMWRITE_PNG_COMPRESSION = 16;
+       const IMWRITE_EXR_TYPE = 3 << 4;
+       const IMWRITE_EXR_COMPRESSION = (3 << 4) + 1;
+    }
+}
+
+bitflags! {
+    pub struct ImwriteEXRTypeFlags: i32 {
+       const IMWRITE_EXR_TYPE_HALF = 1;
+       const IMWRITE_EXR_TYPE_FLOAT = 2;
+    }
+}
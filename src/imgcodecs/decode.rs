@@ -1,5 +1,8 @@
 use super::consts::ImreadModes;
-use crate::{core::Mat, result::Result};
+use crate::{
+    core::{DynMat, Mat},
+    result::Result,
+};
 
 mod ffi {
     use crate::{core::MatPointer, ffi::FFIResult};
@@ -11,6 +14,10 @@ mod ffi {
             size: usize,
             flags: i32,
         ) -> FFIResult<*const MatPointer>;
+        pub(super) fn cv_imdecode_bgra(
+            data: *const u8,
+            size: usize,
+        ) -> FFIResult<*const MatPointer>;
     }
 }
 
@@ -36,9 +43,30 @@ impl Mat<u8, 3> {
     }
 }
 
+impl Mat<u8, 4> {
+    /// Decode with `IMREAD_UNCHANGED`, converting to 8-bit BGRA.
+    /// Images without an alpha channel get an opaque one, 16-bit images are
+    /// scaled down by 256 and float images are taken to lie in `[0, 1]`.
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let pointer = Result::from(unsafe { ffi::cv_imdecode_bgra(data.as_ptr(), data.len()) })?;
+        Ok(Mat::from_ptr(pointer))
+    }
+}
+
+impl DynMat {
+    /// Decode keeping whatever type `flags` produces, e.g. 16-bit or 4-channel
+    /// images with `IMREAD_UNCHANGED`.
+    pub fn decode(data: &[u8], flags: ImreadModes) -> Result<Self> {
+        let pointer =
+            Result::from(unsafe { ffi::cv_imdecode(data.as_ptr(), data.len(), flags.bits()) })?;
+        Ok(DynMat::from_ptr(pointer))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{core::DataTypes, imgcodecs::Ext};
 
     #[test]
     fn imdecode_test() {
@@ -51,4 +79,42 @@ mod tests {
         assert_eq!(src.data().len(), 7 * 6 * 3);
         assert!(src.data().iter().all(|&value| value == 255));
     }
+
+    #[test]
+    fn imdecode_bgra_test() {
+        let data: &[u8] = include_bytes!("../../mock/7x6_square_5x4.png");
+        let src = Mat::<u8, 4>::decode(data).unwrap();
+        assert_eq!(src.cols(), 7);
+        assert_eq!(src.rows(), 6);
+        assert_eq!(src.channels(), 4);
+        assert_eq!(src.at(0, 0), &[0, 0, 0, 255]);
+        assert_eq!(src.at(1, 1), &[255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn imdecode_bgra_float_test() {
+        // A 32-bit float TIFF holding 0.5 and 1.0.
+        let data: &[u8] = include_bytes!("../../mock/1x2_float.tiff");
+        let src = Mat::<u8, 4>::decode(data).unwrap();
+        assert_eq!((src.rows(), src.cols()), (1, 2));
+        assert_eq!(src.at(0, 0), &[128, 128, 128, 255]);
+        assert_eq!(src.at(0, 1), &[255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn imdecode_dyn_test() {
+        let src = Mat::<u16, 1>::from_fn(3, 2, |r, c| [(r * 1000 + c) as u16]).unwrap();
+        let data = src.encode(Ext::PNG).unwrap();
+        let mat = DynMat::decode(data.data(), ImreadModes::IMREAD_UNCHANGED).unwrap();
+        assert_eq!(mat.data_type(), Some(DataTypes::CV_16UC1));
+        let mat: Mat<u16, 1> = mat.try_into().unwrap();
+        assert_eq!(mat.data(), src.data());
+
+        let src = Mat::<u8, 4>::full(2, 2, [1, 2, 3, 4]).unwrap();
+        let data = src.encode(Ext::PNG).unwrap();
+        let mat = DynMat::decode(data.data(), ImreadModes::IMREAD_UNCHANGED).unwrap();
+        assert_eq!(mat.data_type(), Some(DataTypes::CV_8UC4));
+        let mat = DynMat::decode(data.data(), ImreadModes::IMREAD_GRAYSCALE).unwrap();
+        assert!(mat.is::<u8, 1>());
+    }
 }
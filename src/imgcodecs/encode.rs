@@ -1,5 +1,6 @@
+use super::consts::{ImwriteEXRTypeFlags, ImwriteFlags};
 use crate::{
-    core::{Bytes, Mat},
+    core::{f16, Bytes, Mat},
     result::Result,
 };
 
@@ -16,6 +17,13 @@ mod ffi {
             dst: *const BytesPointer,
             ext: *const std::ffi::c_char,
         ) -> FFIResult<i32>;
+        pub(super) fn cv_imencode_with_params(
+            src: *const MatPointer,
+            dst: *const BytesPointer,
+            ext: *const std::ffi::c_char,
+            params: *const i32,
+            size: usize,
+        ) -> FFIResult<i32>;
     }
 }
 
@@ -26,11 +34,28 @@ impl<T, const C: usize> Mat<T, C> {
         Result::from(unsafe { ffi::cv_imencode(self.pointer, bytes.pointer, ext.as_ptr()) })?;
         Ok(bytes)
     }
+
+    /// `params` are `(ImwriteFlags, value)` pairs flattened, as in `cv::imencode`.
+    pub fn encode_with_params(&self, ext: Ext, params: &[i32]) -> Result<Bytes> {
+        let ext = std::ffi::CString::new(ext.to_string()).unwrap();
+        let bytes = Bytes::default();
+        Result::from(unsafe {
+            ffi::cv_imencode_with_params(
+                self.pointer,
+                bytes.pointer,
+                ext.as_ptr(),
+                params.as_ptr(),
+                params.len(),
+            )
+        })?;
+        Ok(bytes)
+    }
 }
 
 pub enum Ext {
     PNG,
     JPG,
+    EXR,
 }
 
 impl std::string::ToString for Ext {
@@ -38,6 +63,7 @@ impl std::string::ToString for Ext {
         match self {
             Ext::PNG => ".png".to_string(),
             Ext::JPG => ".jpg".to_string(),
+            Ext::EXR => ".exr".to_string(),
         }
     }
 }
@@ -52,9 +78,36 @@ impl<const C: usize> Mat<u8, C> {
     }
 }
 
+// OpenCV only reads and writes EXR when OPENCV_IO_ENABLE_OPENEXR is set.
+impl<const C: usize> Mat<f32, C> {
+    pub fn encode_exr(&self) -> Result<Bytes> {
+        self.encode_with_params(
+            Ext::EXR,
+            &[
+                ImwriteFlags::IMWRITE_EXR_TYPE.bits(),
+                ImwriteEXRTypeFlags::IMWRITE_EXR_TYPE_FLOAT.bits(),
+            ],
+        )
+    }
+}
+
+impl<const C: usize> Mat<f16, C> {
+    /// Stored as half floats. The EXR encoder takes `f32` input, so the Mat is widened first.
+    pub fn encode_exr(&self) -> Result<Bytes> {
+        self.convert_to::<f32>(1., 0.)?.encode_with_params(
+            Ext::EXR,
+            &[
+                ImwriteFlags::IMWRITE_EXR_TYPE.bits(),
+                ImwriteEXRTypeFlags::IMWRITE_EXR_TYPE_HALF.bits(),
+            ],
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{core::DynMat, imgcodecs::ImreadModes};
 
     #[test]
     fn imencode_test() {
@@ -66,4 +119,23 @@ mod tests {
         assert_eq!(a.data_type(), b.data_type());
         assert_eq!(a.data(), b.data());
     }
+
+    // OpenCV reads the variable once, so it has to be set before the tests start.
+    #[test]
+    #[ignore = "needs OPENCV_IO_ENABLE_OPENEXR=1"]
+    fn imencode_exr_test() {
+        let data = (0..4 * 3).map(|v| v as f32 / 8.).collect::<Vec<_>>();
+        let src = Mat::<f32, 3>::from_shape_vec(2, 2, &data).unwrap();
+        let encoded = src.encode_exr().unwrap();
+        assert_eq!(&encoded.data()[..4], &[0x76, 0x2f, 0x31, 0x01]);
+        let decoded = DynMat::decode(encoded.data(), ImreadModes::IMREAD_UNCHANGED).unwrap();
+        let decoded: Mat<f32, 3> = decoded.try_into().unwrap();
+        assert_eq!(decoded.data(), src.data());
+
+        let half = src.convert_to::<f16>(1., 0.).unwrap();
+        let encoded = half.encode_exr().unwrap();
+        let decoded = DynMat::decode(encoded.data(), ImreadModes::IMREAD_UNCHANGED).unwrap();
+        let decoded: Mat<f32, 3> = decoded.try_into().unwrap();
+        assert_eq!(decoded.data(), src.data());
+    }
 }
@@ -1,5 +1,8 @@
 use super::consts::ImreadModes;
-use crate::{core::Mat, result::Result};
+use crate::{
+    core::{DynMat, Mat},
+    result::Result,
+};
 use std::ffi::CString;
 
 mod ffi {
@@ -9,6 +12,7 @@ mod ffi {
     #[link(name = "rxcv", kind = "static")]
     extern "C" {
         pub(super) fn cv_imread(path: *const c_char, flags: i32) -> FFIResult<*const MatPointer>;
+        pub(super) fn cv_imread_bgra(path: *const c_char) -> FFIResult<*const MatPointer>;
     }
 }
 
@@ -38,6 +42,29 @@ impl Mat<u8, 3> {
     }
 }
 
+impl Mat<u8, 4> {
+    /// Read with `IMREAD_UNCHANGED`, converting to 8-bit BGRA as [`Mat::decode`] does.
+    pub fn read(path: &str) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        let path = CString::new(path).unwrap();
+        let path = path.as_ptr();
+        let pointer = Result::from(unsafe { ffi::cv_imread_bgra(path) })?;
+        Ok(Mat::from_ptr(pointer))
+    }
+}
+
+impl DynMat {
+    /// Read keeping whatever type `flags` produces.
+    pub fn read(path: &str, flags: ImreadModes) -> Result<Self> {
+        let path = CString::new(path).unwrap();
+        let path = path.as_ptr();
+        let pointer = Result::from(unsafe { ffi::cv_imread(path, flags.bits()) })?;
+        Ok(DynMat::from_ptr(pointer))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -52,4 +79,12 @@ mod tests {
         assert_eq!(src.data().len(), 7 * 6 * 3);
         assert!(src.data().iter().all(|&value| value == 255));
     }
+
+    #[test]
+    fn imread_bgra_test() {
+        let src = Mat::<u8, 4>::read("mock/7x6_white.png").unwrap();
+        assert_eq!(src.channels(), 4);
+        assert_eq!(src.size(), 7 * 6 * 4);
+        assert!(src.data().iter().all(|&value| value == 255));
+    }
 }
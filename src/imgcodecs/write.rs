@@ -7,7 +7,7 @@ mod ffi {
 
     #[link(name = "rxcv", kind = "static")]
     extern "C" {
-        pub(super) fn cv_imwrite(img: *const MatPointer, path: *const c_char) -> FFIResult<()>;
+        pub(super) fn cv_imwrite(img: *const MatPointer, path: *const c_char) -> FFIResult<i32>;
     }
 }
 
@@ -21,7 +21,8 @@ macro_rules! impl_imwrite {
             fn write(&self, path: &str) -> Result<()> {
                 let path = CString::new(path).unwrap();
                 let path = path.as_ptr();
-                Result::from(unsafe { ffi::cv_imwrite(self.pointer, path) })
+                Result::from(unsafe { ffi::cv_imwrite(self.pointer, path) })?;
+                Ok(())
             }
         }
     };
@@ -29,3 +30,4 @@ macro_rules! impl_imwrite {
 
 impl_imwrite!(u8, 1);
 impl_imwrite!(u8, 3);
+impl_imwrite!(u8, 4);
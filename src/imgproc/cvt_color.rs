@@ -38,6 +38,56 @@ impl<T> Mat<T, 3> {
         })?;
         Ok(dst)
     }
+
+    pub fn cvt_color_bgr2bgra(&self) -> Result<Mat<T, 4>> {
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_cvt_color(
+                self.pointer,
+                dst.pointer,
+                ColorConversionCodes::COLOR_BGR2BGRA.bits(),
+            )
+        })?;
+        Ok(dst)
+    }
+}
+
+impl<T> Mat<T, 4> {
+    pub fn cvt_color_bgra2bgr(&self) -> Result<Mat<T, 3>> {
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_cvt_color(
+                self.pointer,
+                dst.pointer,
+                ColorConversionCodes::COLOR_BGRA2BGR.bits(),
+            )
+        })?;
+        Ok(dst)
+    }
+
+    pub fn cvt_color_bgra2gray(&self) -> Result<Mat<T, 1>> {
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_cvt_color(
+                self.pointer,
+                dst.pointer,
+                ColorConversionCodes::COLOR_BGRA2GRAY.bits(),
+            )
+        })?;
+        Ok(dst)
+    }
+
+    pub fn cvt_color_bgra2rgba(&self) -> Result<Self> {
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_cvt_color(
+                self.pointer,
+                dst.pointer,
+                ColorConversionCodes::COLOR_BGRA2RGBA.bits(),
+            )
+        })?;
+        Ok(dst)
+    }
 }
 
 impl<T> Mat<T, 1> {
@@ -52,6 +102,18 @@ impl<T> Mat<T, 1> {
         })?;
         Ok(dst)
     }
+
+    pub fn cvt_color_gray2bgra(&self) -> Result<Mat<T, 4>> {
+        let dst = Mat::new()?;
+        Result::from(unsafe {
+            ffi::cv_cvt_color(
+                self.pointer,
+                dst.pointer,
+                ColorConversionCodes::COLOR_GRAY2BGRA.bits(),
+            )
+        })?;
+        Ok(dst)
+    }
 }
 
 #[cfg(test)]
@@ -73,4 +135,22 @@ mod tests {
         assert_eq!(src.channels(), 3);
         assert_eq!(hsv.channels(), 3);
     }
+
+    #[test]
+    fn cvt_color_bgra_test() {
+        let src = Mat::mock_7x6_square_5x4();
+        let bgra = src.cvt_color_bgr2bgra().unwrap();
+        assert_eq!(bgra.channels(), 4);
+        assert_eq!(bgra.at(0, 0), &[0, 0, 0, 255]);
+        assert_eq!(bgra.at(1, 1), &[255, 255, 255, 255]);
+        let bgr = bgra.cvt_color_bgra2bgr().unwrap();
+        assert_eq!(bgr.data(), src.data());
+        let rgba = bgra.cvt_color_bgra2rgba().unwrap();
+        assert_eq!(rgba.channels(), 4);
+        let gray = bgra.cvt_color_bgra2gray().unwrap();
+        assert_eq!(gray.channels(), 1);
+        let bgra = gray.cvt_color_gray2bgra().unwrap();
+        assert_eq!(bgra.channels(), 4);
+        assert_eq!(bgra.at(1, 1), &[255, 255, 255, 255]);
+    }
 }
@@ -1,5 +1,8 @@
 use super::consts::{ContourApproximationModes, RetrievalModes};
-use crate::core::{Contours, Mat};
+use crate::{
+    core::{Contours, Mat},
+    result::Result,
+};
 
 mod ffi {
     use crate::{
@@ -23,7 +26,7 @@ impl<T> Mat<T, 1> {
         &self,
         mode: RetrievalModes,
         method: ContourApproximationModes,
-    ) -> Result<Contours, &'static str>
+    ) -> Result<Contours>
     where
         Self: Sized,
     {
@@ -134,6 +134,7 @@ macro_rules! impl_remap_all {
         impl_remap!($t, 1, $i);
         impl_remap!($t, 2, $i);
         impl_remap!($t, 3, $i);
+        impl_remap!($t, 4, $i);
     };
 }
 
@@ -225,18 +226,23 @@ mod tests {
     impl_remap_test!(remap_8uc1_test, u8, 1);
     impl_remap_test!(remap_8uc2_test, u8, 2);
     impl_remap_test!(remap_8uc3_test, u8, 3);
+    impl_remap_test!(remap_8uc4_test, u8, 4);
     impl_remap_test!(remap_16uc1_test, u16, 1);
     impl_remap_test!(remap_16uc2_test, u16, 2);
     impl_remap_test!(remap_16uc3_test, u16, 3);
+    impl_remap_test!(remap_16uc4_test, u16, 4);
     impl_remap_test!(remap_16sc1_test, i16, 1);
     impl_remap_test!(remap_16sc2_test, i16, 2);
     impl_remap_test!(remap_16sc3_test, i16, 3);
+    impl_remap_test!(remap_16sc4_test, i16, 4);
     impl_remap_test!(remap_32fc1_test, f32, 1);
     impl_remap_test!(remap_32fc2_test, f32, 2);
     impl_remap_test!(remap_32fc3_test, f32, 3);
+    impl_remap_test!(remap_32fc4_test, f32, 4);
     impl_remap_test!(remap_64fc1_test, f64, 1);
     impl_remap_test!(remap_64fc2_test, f64, 2);
     impl_remap_test!(remap_64fc3_test, f64, 3);
+    impl_remap_test!(remap_64fc4_test, f64, 4);
 }
 
 mod i8 {
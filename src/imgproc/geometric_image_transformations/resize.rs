@@ -83,6 +83,7 @@ macro_rules! impl_resize_all {
         impl_resize!($t, 1);
         impl_resize!($t, 2);
         impl_resize!($t, 3);
+        impl_resize!($t, 4);
     };
 }
 
@@ -193,6 +194,7 @@ macro_rules! impl_module {
             impl_resize!(1);
             impl_resize!(2);
             impl_resize!(3);
+            impl_resize!(4);
 
             #[cfg(test)]
             mod tests {
@@ -49,21 +49,27 @@ macro_rules! impl_blur {
 impl_blur!(u8, 1);
 impl_blur!(u8, 2);
 impl_blur!(u8, 3);
+impl_blur!(u8, 4);
 impl_blur!(u16, 1);
 impl_blur!(u16, 2);
 impl_blur!(u16, 3);
+impl_blur!(u16, 4);
 impl_blur!(i16, 1);
 impl_blur!(i16, 2);
 impl_blur!(i16, 3);
+impl_blur!(i16, 4);
 impl_blur!(i32, 1);
 impl_blur!(i32, 2);
 impl_blur!(i32, 3);
+impl_blur!(i32, 4);
 impl_blur!(f32, 1);
 impl_blur!(f32, 2);
 impl_blur!(f32, 3);
+impl_blur!(f32, 4);
 impl_blur!(f64, 1);
 impl_blur!(f64, 2);
 impl_blur!(f64, 3);
+impl_blur!(f64, 4);
 
 #[cfg(test)]
 mod tests {
@@ -78,7 +84,7 @@ mod tests {
                     width: 10,
                     height: 10,
                 };
-                let anchor = Point::default();
+                let anchor = Point::ANCHOR_CENTER;
                 let dst = src
                     .blur(ksize, anchor, BorderTypes::BORDER_DEFAULT)
                     .unwrap();
@@ -91,19 +97,25 @@ mod tests {
     blur_test!(blur_8uc1_test, u8, 1);
     blur_test!(blur_8uc2_test, u8, 2);
     blur_test!(blur_8uc3_test, u8, 3);
+    blur_test!(blur_8uc4_test, u8, 4);
     blur_test!(blur_16uc1_test, u16, 1);
     blur_test!(blur_16uc2_test, u16, 2);
     blur_test!(blur_16uc3_test, u16, 3);
+    blur_test!(blur_16uc4_test, u16, 4);
     blur_test!(blur_16sc1_test, i16, 1);
     blur_test!(blur_16sc2_test, i16, 2);
     blur_test!(blur_16sc3_test, i16, 3);
+    blur_test!(blur_16sc4_test, i16, 4);
     blur_test!(blur_32sc1_test, i32, 1);
     blur_test!(blur_32sc2_test, i32, 2);
     blur_test!(blur_32sc3_test, i32, 3);
+    blur_test!(blur_32sc4_test, i32, 4);
     blur_test!(blur_32fc1_test, f32, 1);
     blur_test!(blur_32fc2_test, f32, 2);
     blur_test!(blur_32fc3_test, f32, 3);
+    blur_test!(blur_32fc4_test, f32, 4);
     blur_test!(blur_64fc1_test, f64, 1);
     blur_test!(blur_64fc2_test, f64, 2);
     blur_test!(blur_64fc3_test, f64, 3);
+    blur_test!(blur_64fc4_test, f64, 4);
 }
@@ -69,18 +69,23 @@ macro_rules! impl_dilate {
 impl_dilate!(u8, 1);
 impl_dilate!(u8, 2);
 impl_dilate!(u8, 3);
+impl_dilate!(u8, 4);
 impl_dilate!(u16, 1);
 impl_dilate!(u16, 2);
 impl_dilate!(u16, 3);
+impl_dilate!(u16, 4);
 impl_dilate!(i16, 1);
 impl_dilate!(i16, 2);
 impl_dilate!(i16, 3);
+impl_dilate!(i16, 4);
 impl_dilate!(f32, 1);
 impl_dilate!(f32, 2);
 impl_dilate!(f32, 3);
+impl_dilate!(f32, 4);
 impl_dilate!(f64, 1);
 impl_dilate!(f64, 2);
 impl_dilate!(f64, 3);
+impl_dilate!(f64, 4);
 
 #[cfg(test)]
 mod tests {
@@ -95,7 +100,7 @@ mod tests {
                     ndarray::Array::from_shape_vec((3, 3, 1), vec![0, 1, 0, 1, 1, 1, 0, 1, 0])
                         .unwrap();
                 let kernel = Mat::<u8, 1>::from_ndarray(&data).unwrap();
-                let anchor = Point::default();
+                let anchor = Point::ANCHOR_CENTER;
                 let dst = src
                     .dilate(kernel, anchor, 1, BorderTypes::BORDER_DEFAULT)
                     .unwrap();
@@ -108,16 +113,21 @@ mod tests {
     dilate_test!(dilate_8uc1_test, u8, 1);
     dilate_test!(dilate_8uc2_test, u8, 2);
     dilate_test!(dilate_8uc3_test, u8, 3);
+    dilate_test!(dilate_8uc4_test, u8, 4);
     dilate_test!(dilate_16uc1_test, u16, 1);
     dilate_test!(dilate_16uc2_test, u16, 2);
     dilate_test!(dilate_16uc3_test, u16, 3);
+    dilate_test!(dilate_16uc4_test, u16, 4);
     dilate_test!(dilate_16sc1_test, i16, 1);
     dilate_test!(dilate_16sc2_test, i16, 2);
     dilate_test!(dilate_16sc3_test, i16, 3);
+    dilate_test!(dilate_16sc4_test, i16, 4);
     dilate_test!(dilate_32fc1_test, f32, 1);
     dilate_test!(dilate_32fc2_test, f32, 2);
     dilate_test!(dilate_32fc3_test, f32, 3);
+    dilate_test!(dilate_32fc4_test, f32, 4);
     dilate_test!(dilate_64fc1_test, f64, 1);
     dilate_test!(dilate_64fc2_test, f64, 2);
     dilate_test!(dilate_64fc3_test, f64, 3);
+    dilate_test!(dilate_64fc4_test, f64, 4);
 }
@@ -69,18 +69,23 @@ macro_rules! impl_erode {
 impl_erode!(u8, 1);
 impl_erode!(u8, 2);
 impl_erode!(u8, 3);
+impl_erode!(u8, 4);
 impl_erode!(u16, 1);
 impl_erode!(u16, 2);
 impl_erode!(u16, 3);
+impl_erode!(u16, 4);
 impl_erode!(i16, 1);
 impl_erode!(i16, 2);
 impl_erode!(i16, 3);
+impl_erode!(i16, 4);
 impl_erode!(f32, 1);
 impl_erode!(f32, 2);
 impl_erode!(f32, 3);
+impl_erode!(f32, 4);
 impl_erode!(f64, 1);
 impl_erode!(f64, 2);
 impl_erode!(f64, 3);
+impl_erode!(f64, 4);
 
 #[cfg(test)]
 mod tests {
@@ -95,7 +100,7 @@ mod tests {
                     ndarray::Array::from_shape_vec((3, 3, 1), vec![0, 1, 0, 1, 1, 1, 0, 1, 0])
                         .unwrap();
                 let kernel = Mat::<u8, 1>::from_ndarray(&data).unwrap();
-                let anchor = Point::default();
+                let anchor = Point::ANCHOR_CENTER;
                 let dst = src
                     .erode(kernel, anchor, 1, BorderTypes::BORDER_DEFAULT)
                     .unwrap();
@@ -108,16 +113,21 @@ mod tests {
     erode_test!(erode_8uc1_test, u8, 1);
     erode_test!(erode_8uc2_test, u8, 2);
     erode_test!(erode_8uc3_test, u8, 3);
+    erode_test!(erode_8uc4_test, u8, 4);
     erode_test!(erode_16uc1_test, u16, 1);
     erode_test!(erode_16uc2_test, u16, 2);
     erode_test!(erode_16uc3_test, u16, 3);
+    erode_test!(erode_16uc4_test, u16, 4);
     erode_test!(erode_16sc1_test, i16, 1);
     erode_test!(erode_16sc2_test, i16, 2);
     erode_test!(erode_16sc3_test, i16, 3);
+    erode_test!(erode_16sc4_test, i16, 4);
     erode_test!(erode_32fc1_test, f32, 1);
     erode_test!(erode_32fc2_test, f32, 2);
     erode_test!(erode_32fc3_test, f32, 3);
+    erode_test!(erode_32fc4_test, f32, 4);
     erode_test!(erode_64fc1_test, f64, 1);
     erode_test!(erode_64fc2_test, f64, 2);
     erode_test!(erode_64fc3_test, f64, 3);
+    erode_test!(erode_64fc4_test, f64, 4);
 }
@@ -69,54 +69,75 @@ macro_rules! impl_gaussian_blur {
 impl_gaussian_blur!(u8, 1);
 impl_gaussian_blur!(u8, 2);
 impl_gaussian_blur!(u8, 3);
+impl_gaussian_blur!(u8, 4);
 impl_gaussian_blur!(u16, 1);
 impl_gaussian_blur!(u16, 2);
 impl_gaussian_blur!(u16, 3);
+impl_gaussian_blur!(u16, 4);
 impl_gaussian_blur!(i16, 1);
 impl_gaussian_blur!(i16, 2);
 impl_gaussian_blur!(i16, 3);
+impl_gaussian_blur!(i16, 4);
 impl_gaussian_blur!(f32, 1);
 impl_gaussian_blur!(f32, 2);
 impl_gaussian_blur!(f32, 3);
+impl_gaussian_blur!(f32, 4);
 impl_gaussian_blur!(f64, 1);
 impl_gaussian_blur!(f64, 2);
 impl_gaussian_blur!(f64, 3);
+impl_gaussian_blur!(f64, 4);
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{Rng, Scalar};
 
     macro_rules! gaussian_blur_test {
         ($name:ident, $t: ty, $c: tt) => {
             #[test]
             fn $name() {
-                let src = Mat::<$t, $c>::from_shape(32, 32).unwrap();
+                let noise = || {
+                    let mut src = Mat::<$t, $c>::zeros(32, 32).unwrap();
+                    Rng::new(0x5eed)
+                        .randu(&mut src, Scalar::all(0.), Scalar::all(100.))
+                        .unwrap();
+                    src
+                };
                 let ksize = Size {
                     width: 3,
                     height: 3,
                 };
-                let dst = src
+                let dst = noise()
                     .gaussian_blur(ksize, 1., 0., BorderTypes::BORDER_DEFAULT)
                     .unwrap();
                 assert_eq!(dst.channels(), $c);
                 assert_eq!(dst.rows(), 32);
                 assert_eq!(dst.cols(), 32);
+                let again = noise()
+                    .gaussian_blur(ksize, 1., 0., BorderTypes::BORDER_DEFAULT)
+                    .unwrap();
+                assert_eq!(dst.data(), again.data());
             }
         };
     }
     gaussian_blur_test!(gaussian_blur_8uc1_test, u8, 1);
     gaussian_blur_test!(gaussian_blur_8uc2_test, u8, 2);
     gaussian_blur_test!(gaussian_blur_8uc3_test, u8, 3);
+    gaussian_blur_test!(gaussian_blur_8uc4_test, u8, 4);
     gaussian_blur_test!(gaussian_blur_16uc1_test, u16, 1);
     gaussian_blur_test!(gaussian_blur_16uc2_test, u16, 2);
     gaussian_blur_test!(gaussian_blur_16uc3_test, u16, 3);
+    gaussian_blur_test!(gaussian_blur_16uc4_test, u16, 4);
     gaussian_blur_test!(gaussian_blur_16sc1_test, i16, 1);
     gaussian_blur_test!(gaussian_blur_16sc2_test, i16, 2);
     gaussian_blur_test!(gaussian_blur_16sc3_test, i16, 3);
+    gaussian_blur_test!(gaussian_blur_16sc4_test, i16, 4);
     gaussian_blur_test!(gaussian_blur_32fc1_test, f32, 1);
     gaussian_blur_test!(gaussian_blur_32fc2_test, f32, 2);
     gaussian_blur_test!(gaussian_blur_32fc3_test, f32, 3);
+    gaussian_blur_test!(gaussian_blur_32fc4_test, f32, 4);
     gaussian_blur_test!(gaussian_blur_64fc1_test, f64, 1);
     gaussian_blur_test!(gaussian_blur_64fc2_test, f64, 2);
     gaussian_blur_test!(gaussian_blur_64fc3_test, f64, 3);
+    gaussian_blur_test!(gaussian_blur_64fc4_test, f64, 4);
 }
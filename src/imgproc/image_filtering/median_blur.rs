@@ -38,15 +38,19 @@ macro_rules! impl_median_blur {
 impl_median_blur!(u8, 1);
 impl_median_blur!(u8, 2);
 impl_median_blur!(u8, 3);
+impl_median_blur!(u8, 4);
 impl_median_blur!(u16, 1);
 impl_median_blur!(u16, 2);
 impl_median_blur!(u16, 3);
+impl_median_blur!(u16, 4);
 impl_median_blur!(i16, 1);
 impl_median_blur!(i16, 2);
 impl_median_blur!(i16, 3);
+impl_median_blur!(i16, 4);
 impl_median_blur!(f32, 1);
 impl_median_blur!(f32, 2);
 impl_median_blur!(f32, 3);
+impl_median_blur!(f32, 4);
 
 #[cfg(test)]
 mod tests {
@@ -66,13 +70,17 @@ mod tests {
     median_blur_test!(median_blur_8uc1_test, u8, 1);
     median_blur_test!(median_blur_8uc2_test, u8, 2);
     median_blur_test!(median_blur_8uc3_test, u8, 3);
+    median_blur_test!(median_blur_8uc4_test, u8, 4);
     median_blur_test!(median_blur_16uc1_test, u16, 1);
     median_blur_test!(median_blur_16uc2_test, u16, 2);
     median_blur_test!(median_blur_16uc3_test, u16, 3);
+    median_blur_test!(median_blur_16uc4_test, u16, 4);
     median_blur_test!(median_blur_16sc1_test, i16, 1);
     median_blur_test!(median_blur_16sc2_test, i16, 2);
     median_blur_test!(median_blur_16sc3_test, i16, 3);
+    median_blur_test!(median_blur_16sc4_test, i16, 4);
     median_blur_test!(median_blur_32fc1_test, f32, 1);
     median_blur_test!(median_blur_32fc2_test, f32, 2);
     median_blur_test!(median_blur_32fc3_test, f32, 3);
+    median_blur_test!(median_blur_32fc4_test, f32, 4);
 }
@@ -74,18 +74,23 @@ macro_rules! impl_morphology_ex {
 impl_morphology_ex!(u8, 1);
 impl_morphology_ex!(u8, 2);
 impl_morphology_ex!(u8, 3);
+impl_morphology_ex!(u8, 4);
 impl_morphology_ex!(u16, 1);
 impl_morphology_ex!(u16, 2);
 impl_morphology_ex!(u16, 3);
+impl_morphology_ex!(u16, 4);
 impl_morphology_ex!(i16, 1);
 impl_morphology_ex!(i16, 2);
 impl_morphology_ex!(i16, 3);
+impl_morphology_ex!(i16, 4);
 impl_morphology_ex!(f32, 1);
 impl_morphology_ex!(f32, 2);
 impl_morphology_ex!(f32, 3);
+impl_morphology_ex!(f32, 4);
 impl_morphology_ex!(f64, 1);
 impl_morphology_ex!(f64, 2);
 impl_morphology_ex!(f64, 3);
+impl_morphology_ex!(f64, 4);
 
 #[cfg(test)]
 mod tests {
@@ -100,7 +105,7 @@ mod tests {
                     ndarray::Array::from_shape_vec((3, 3, 1), vec![0, 1, 0, 1, 1, 1, 0, 1, 0])
                         .unwrap();
                 let kernel = Mat::<u8, 1>::from_ndarray(&data).unwrap();
-                let anchor = Point::default();
+                let anchor = Point::ANCHOR_CENTER;
                 let dst = src
                     .morphology_ex(
                         MorphTypes::MORPH_OPEN,
@@ -119,16 +124,21 @@ mod tests {
     morphology_ex_test!(morphology_ex_8uc1_test, u8, 1);
     morphology_ex_test!(morphology_ex_8uc2_test, u8, 2);
     morphology_ex_test!(morphology_ex_8uc3_test, u8, 3);
+    morphology_ex_test!(morphology_ex_8uc4_test, u8, 4);
     morphology_ex_test!(morphology_ex_16uc1_test, u16, 1);
     morphology_ex_test!(morphology_ex_16uc2_test, u16, 2);
     morphology_ex_test!(morphology_ex_16uc3_test, u16, 3);
+    morphology_ex_test!(morphology_ex_16uc4_test, u16, 4);
     morphology_ex_test!(morphology_ex_16sc1_test, i16, 1);
     morphology_ex_test!(morphology_ex_16sc2_test, i16, 2);
     morphology_ex_test!(morphology_ex_16sc3_test, i16, 3);
+    morphology_ex_test!(morphology_ex_16sc4_test, i16, 4);
     morphology_ex_test!(morphology_ex_32fc1_test, f32, 1);
     morphology_ex_test!(morphology_ex_32fc2_test, f32, 2);
     morphology_ex_test!(morphology_ex_32fc3_test, f32, 3);
+    morphology_ex_test!(morphology_ex_32fc4_test, f32, 4);
     morphology_ex_test!(morphology_ex_64fc1_test, f64, 1);
     morphology_ex_test!(morphology_ex_64fc2_test, f64, 2);
     morphology_ex_test!(morphology_ex_64fc3_test, f64, 3);
+    morphology_ex_test!(morphology_ex_64fc4_test, f64, 4);
 }
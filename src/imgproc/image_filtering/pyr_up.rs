@@ -41,18 +41,23 @@ macro_rules! impl_pyr_up {
 impl_pyr_up!(u8, 1);
 impl_pyr_up!(u8, 2);
 impl_pyr_up!(u8, 3);
+impl_pyr_up!(u8, 4);
 impl_pyr_up!(u16, 1);
 impl_pyr_up!(u16, 2);
 impl_pyr_up!(u16, 3);
+impl_pyr_up!(u16, 4);
 impl_pyr_up!(i16, 1);
 impl_pyr_up!(i16, 2);
 impl_pyr_up!(i16, 3);
+impl_pyr_up!(i16, 4);
 impl_pyr_up!(f32, 1);
 impl_pyr_up!(f32, 2);
 impl_pyr_up!(f32, 3);
+impl_pyr_up!(f32, 4);
 impl_pyr_up!(f64, 1);
 impl_pyr_up!(f64, 2);
 impl_pyr_up!(f64, 3);
+impl_pyr_up!(f64, 4);
 
 #[cfg(test)]
 mod tests {
@@ -76,16 +81,21 @@ mod tests {
     pyr_up_test!(pyr_up_8uc1_test, u8, 1);
     pyr_up_test!(pyr_up_8uc2_test, u8, 2);
     pyr_up_test!(pyr_up_8uc3_test, u8, 3);
+    pyr_up_test!(pyr_up_8uc4_test, u8, 4);
     pyr_up_test!(pyr_up_16uc1_test, u16, 1);
     pyr_up_test!(pyr_up_16uc2_test, u16, 2);
     pyr_up_test!(pyr_up_16uc3_test, u16, 3);
+    pyr_up_test!(pyr_up_16uc4_test, u16, 4);
     pyr_up_test!(pyr_up_16sc1_test, i16, 1);
     pyr_up_test!(pyr_up_16sc2_test, i16, 2);
     pyr_up_test!(pyr_up_16sc3_test, i16, 3);
+    pyr_up_test!(pyr_up_16sc4_test, i16, 4);
     pyr_up_test!(pyr_up_32fc1_test, f32, 1);
     pyr_up_test!(pyr_up_32fc2_test, f32, 2);
     pyr_up_test!(pyr_up_32fc3_test, f32, 3);
+    pyr_up_test!(pyr_up_32fc4_test, f32, 4);
     pyr_up_test!(pyr_up_64fc1_test, f64, 1);
     pyr_up_test!(pyr_up_64fc2_test, f64, 2);
     pyr_up_test!(pyr_up_64fc3_test, f64, 3);
+    pyr_up_test!(pyr_up_64fc4_test, f64, 4);
 }
@@ -99,41 +99,53 @@ mod tests {
     scharr_test!(sep_filter2d_8uc1_to_8uc1_test, u8, u8, 1);
     scharr_test!(sep_filter2d_8uc2_to_8uc2_test, u8, u8, 2);
     scharr_test!(sep_filter2d_8uc3_to_8uc3_test, u8, u8, 3);
+    scharr_test!(sep_filter2d_8uc4_to_8uc4_test, u8, u8, 4);
     scharr_test!(sep_filter2d_8uc1_to_16s1_test, u8, i16, 1);
     scharr_test!(sep_filter2d_8uc2_to_16s2_test, u8, i16, 2);
     scharr_test!(sep_filter2d_8uc3_to_16s3_test, u8, i16, 3);
+    scharr_test!(sep_filter2d_8uc4_to_16s4_test, u8, i16, 4);
     scharr_test!(sep_filter2d_8uc1_to_32fc1_test, u8, f32, 1);
     scharr_test!(sep_filter2d_8uc2_to_32fc2_test, u8, f32, 2);
     scharr_test!(sep_filter2d_8uc3_to_32fc3_test, u8, f32, 3);
+    scharr_test!(sep_filter2d_8uc4_to_32fc4_test, u8, f32, 4);
     scharr_test!(sep_filter2d_8uc1_to_64fc1_test, u8, f64, 1);
     scharr_test!(sep_filter2d_8uc2_to_64fc2_test, u8, f64, 2);
     scharr_test!(sep_filter2d_8uc3_to_64fc3_test, u8, f64, 3);
+    scharr_test!(sep_filter2d_8uc4_to_64fc4_test, u8, f64, 4);
 
     scharr_test!(sep_filter2d_16uc1_to_16uc1_test, u16, u16, 1);
     scharr_test!(sep_filter2d_16uc2_to_16uc2_test, u16, u16, 2);
     scharr_test!(sep_filter2d_16uc3_to_16uc3_test, u16, u16, 3);
+    scharr_test!(sep_filter2d_16uc4_to_16uc4_test, u16, u16, 4);
     scharr_test!(sep_filter2d_16uc1_to_32fc1_test, u16, f32, 1);
     scharr_test!(sep_filter2d_16uc2_to_32fc2_test, u16, f32, 2);
     scharr_test!(sep_filter2d_16uc3_to_32fc3_test, u16, f32, 3);
+    scharr_test!(sep_filter2d_16uc4_to_32fc4_test, u16, f32, 4);
     scharr_test!(sep_filter2d_16uc1_to_64fc1_test, u16, f64, 1);
     scharr_test!(sep_filter2d_16uc2_to_64fc2_test, u16, f64, 2);
     scharr_test!(sep_filter2d_16uc3_to_64fc3_test, u16, f64, 3);
+    scharr_test!(sep_filter2d_16uc4_to_64fc4_test, u16, f64, 4);
 
     scharr_test!(sep_filter2d_16sc1_to_16sc1_test, i16, i16, 1);
     scharr_test!(sep_filter2d_16sc2_to_16sc2_test, i16, i16, 2);
     scharr_test!(sep_filter2d_16sc3_to_16sc3_test, i16, i16, 3);
+    scharr_test!(sep_filter2d_16sc4_to_16sc4_test, i16, i16, 4);
     scharr_test!(sep_filter2d_16sc1_to_32fc1_test, i16, f32, 1);
     scharr_test!(sep_filter2d_16sc2_to_32fc2_test, i16, f32, 2);
     scharr_test!(sep_filter2d_16sc3_to_32fc3_test, i16, f32, 3);
+    scharr_test!(sep_filter2d_16sc4_to_32fc4_test, i16, f32, 4);
     scharr_test!(sep_filter2d_16sc1_to_64fc1_test, i16, f64, 1);
     scharr_test!(sep_filter2d_16sc2_to_64fc2_test, i16, f64, 2);
     scharr_test!(sep_filter2d_16sc3_to_64fc3_test, i16, f64, 3);
+    scharr_test!(sep_filter2d_16sc4_to_64fc4_test, i16, f64, 4);
 
     scharr_test!(sep_filter2d_32fc1_to_32fc1_test, f32, f32, 1);
     scharr_test!(sep_filter2d_32fc2_to_32fc2_test, f32, f32, 2);
     scharr_test!(sep_filter2d_32fc3_to_32fc3_test, f32, f32, 3);
+    scharr_test!(sep_filter2d_32fc4_to_32fc4_test, f32, f32, 4);
 
     scharr_test!(sep_filter2d_64fc1_to_64fc1_test, f64, f64, 1);
     scharr_test!(sep_filter2d_64fc2_to_64fc2_test, f64, f64, 2);
     scharr_test!(sep_filter2d_64fc3_to_64fc3_test, f64, f64, 3);
+    scharr_test!(sep_filter2d_64fc4_to_64fc4_test, f64, f64, 4);
 }
@@ -71,18 +71,23 @@ macro_rules! impl_sqr_box_filter {
 impl_sqr_box_filter!(u8, 1, DataTypes::CV_8U.bits());
 impl_sqr_box_filter!(u8, 2, DataTypes::CV_8U.bits());
 impl_sqr_box_filter!(u8, 3, DataTypes::CV_8U.bits());
+impl_sqr_box_filter!(u8, 4, DataTypes::CV_8U.bits());
 impl_sqr_box_filter!(u16, 1, DataTypes::CV_16U.bits());
 impl_sqr_box_filter!(u16, 2, DataTypes::CV_16U.bits());
 impl_sqr_box_filter!(u16, 3, DataTypes::CV_16U.bits());
+impl_sqr_box_filter!(u16, 4, DataTypes::CV_16U.bits());
 impl_sqr_box_filter!(i16, 1, DataTypes::CV_16S.bits());
 impl_sqr_box_filter!(i16, 2, DataTypes::CV_16S.bits());
 impl_sqr_box_filter!(i16, 3, DataTypes::CV_16S.bits());
+impl_sqr_box_filter!(i16, 4, DataTypes::CV_16S.bits());
 impl_sqr_box_filter!(f32, 1, DataTypes::CV_32F.bits());
 impl_sqr_box_filter!(f32, 2, DataTypes::CV_32F.bits());
 impl_sqr_box_filter!(f32, 3, DataTypes::CV_32F.bits());
+impl_sqr_box_filter!(f32, 4, DataTypes::CV_32F.bits());
 impl_sqr_box_filter!(f64, 1, DataTypes::CV_64F.bits());
 impl_sqr_box_filter!(f64, 2, DataTypes::CV_64F.bits());
 impl_sqr_box_filter!(f64, 3, DataTypes::CV_64F.bits());
+impl_sqr_box_filter!(f64, 4, DataTypes::CV_64F.bits());
 
 #[cfg(test)]
 mod tests {
@@ -97,7 +102,7 @@ mod tests {
                     width: 10,
                     height: 10,
                 };
-                let anchor = Point::default();
+                let anchor = Point::ANCHOR_CENTER;
                 let dst = src
                     .sqr_box_filter(ksize, anchor, true, BorderTypes::BORDER_DEFAULT)
                     .unwrap();
@@ -108,7 +113,7 @@ mod tests {
                     width: 10,
                     height: 10,
                 };
-                let anchor = Point::default();
+                let anchor = Point::ANCHOR_CENTER;
                 let dst = src
                     .sqr_box_filter(ksize, anchor, false, BorderTypes::BORDER_DEFAULT)
                     .unwrap();
@@ -121,16 +126,21 @@ mod tests {
     sqr_box_filter_test!(sqr_box_filter_8uc1_test, u8, 1);
     sqr_box_filter_test!(sqr_box_filter_8uc2_test, u8, 2);
     sqr_box_filter_test!(sqr_box_filter_8uc3_test, u8, 3);
+    sqr_box_filter_test!(sqr_box_filter_8uc4_test, u8, 4);
     sqr_box_filter_test!(sqr_box_filter_16uc1_test, u16, 1);
     sqr_box_filter_test!(sqr_box_filter_16uc2_test, u16, 2);
     sqr_box_filter_test!(sqr_box_filter_16uc3_test, u16, 3);
+    sqr_box_filter_test!(sqr_box_filter_16uc4_test, u16, 4);
     sqr_box_filter_test!(sqr_box_filter_16sc1_test, i16, 1);
     sqr_box_filter_test!(sqr_box_filter_16sc2_test, i16, 2);
     sqr_box_filter_test!(sqr_box_filter_16sc3_test, i16, 3);
+    sqr_box_filter_test!(sqr_box_filter_16sc4_test, i16, 4);
     sqr_box_filter_test!(sqr_box_filter_32fc1_test, f32, 1);
     sqr_box_filter_test!(sqr_box_filter_32fc2_test, f32, 2);
     sqr_box_filter_test!(sqr_box_filter_32fc3_test, f32, 3);
+    sqr_box_filter_test!(sqr_box_filter_32fc4_test, f32, 4);
     sqr_box_filter_test!(sqr_box_filter_64fc1_test, f64, 1);
     sqr_box_filter_test!(sqr_box_filter_64fc2_test, f64, 2);
     sqr_box_filter_test!(sqr_box_filter_64fc3_test, f64, 3);
+    sqr_box_filter_test!(sqr_box_filter_64fc4_test, f64, 4);
 }
@@ -1,5 +1,6 @@
 mod geometric_image_transformations;
 mod image_filtering;
+mod motion_analysis;
 
 mod consts;
 mod cvt_color;
@@ -8,6 +9,7 @@ mod threshold;
 
 pub use geometric_image_transformations::*;
 pub use image_filtering::*;
+pub use motion_analysis::*;
 
 pub use consts::*;
 pub use cvt_color::*;
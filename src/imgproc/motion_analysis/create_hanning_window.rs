@@ -0,0 +1,64 @@
+use crate::{core::Mat, result::Result, Size};
+
+mod ffi {
+    use crate::{core::MatPointer, ffi::FFIResult, Size};
+
+    #[link(name = "rxcv", kind = "static")]
+    extern "C" {
+        pub(super) fn cv_create_hanning_window(
+            dst: *const MatPointer,
+            win_size: Size,
+            type_: i32,
+        ) -> FFIResult<i32>;
+    }
+}
+
+/// Hann window of `win_size`, used to suppress edge effects before
+/// [`phase_correlate`](super::PhaseCorrelate::phase_correlate).
+pub trait CreateHanningWindow
+where
+    Self: Sized,
+{
+    fn create_hanning_window(win_size: Size) -> Result<Self>;
+}
+
+macro_rules! impl_create_hanning_window {
+    ($t:ty) => {
+        impl CreateHanningWindow for Mat<$t, 1> {
+            fn create_hanning_window(win_size: Size) -> Result<Self> {
+                let dst = Mat::new()?;
+                Result::from(unsafe {
+                    ffi::cv_create_hanning_window(dst.pointer, win_size, Self::TYPE)
+                })?;
+                Ok(dst)
+            }
+        }
+    };
+}
+
+impl_create_hanning_window!(f32);
+impl_create_hanning_window!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! create_hanning_window_test {
+        ($name:ident, $t: ty) => {
+            #[test]
+            fn $name() {
+                let size = Size {
+                    width: 5,
+                    height: 3,
+                };
+                let dst = Mat::<$t, 1>::create_hanning_window(size).unwrap();
+                assert_eq!(dst.cols(), 5);
+                assert_eq!(dst.rows(), 3);
+                assert_eq!(dst.at(0, 0), &[0.]);
+                assert!((dst.at(1, 2)[0] - 1.).abs() < 1e-6);
+            }
+        };
+    }
+    create_hanning_window_test!(create_hanning_window_32f_test, f32);
+    create_hanning_window_test!(create_hanning_window_64f_test, f64);
+}
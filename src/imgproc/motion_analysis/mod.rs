@@ -0,0 +1,5 @@
+mod create_hanning_window;
+mod phase_correlate;
+
+pub use create_hanning_window::*;
+pub use phase_correlate::*;
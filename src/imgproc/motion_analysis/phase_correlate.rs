@@ -0,0 +1,80 @@
+use crate::{core::Mat, result::Result, Point2d};
+
+mod ffi {
+    use crate::{core::MatPointer, ffi::FFIResult, Point2d};
+
+    #[link(name = "rxcv", kind = "static")]
+    extern "C" {
+        pub(super) fn cv_phase_correlate(
+            src1: *const MatPointer,
+            src2: *const MatPointer,
+            window: *const MatPointer,
+            response: *mut f64,
+        ) -> FFIResult<Point2d>;
+    }
+}
+
+pub trait PhaseCorrelate
+where
+    Self: Sized,
+{
+    /// Sub-pixel translation of `other` relative to `self` and the peak response,
+    /// between 0 and 1. `window` is usually a
+    /// [`create_hanning_window`](super::CreateHanningWindow::create_hanning_window) of the same size.
+    fn phase_correlate(&self, other: &Self, window: Option<&Self>) -> Result<(Point2d, f64)>;
+}
+
+macro_rules! impl_phase_correlate {
+    ($t:ty) => {
+        impl PhaseCorrelate for Mat<$t, 1> {
+            fn phase_correlate(
+                &self,
+                other: &Self,
+                window: Option<&Self>,
+            ) -> Result<(Point2d, f64)> {
+                let window = window.map_or(std::ptr::null(), |window| window.pointer);
+                let mut response = 0.;
+                let shift = Result::from(unsafe {
+                    ffi::cv_phase_correlate(self.pointer, other.pointer, window, &mut response)
+                })?;
+                Ok((shift, response))
+            }
+        }
+    };
+}
+
+impl_phase_correlate!(f32);
+impl_phase_correlate!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{imgproc::CreateHanningWindow, Size};
+
+    macro_rules! phase_correlate_test {
+        ($name:ident, $t: ty) => {
+            #[test]
+            fn $name() {
+                // A broadband pattern and a copy circularly shifted by (3, 2).
+                let pattern = |r: usize, c: usize| [((r * 7919 + c * c * 104729) % 251) as $t];
+                let src = Mat::<$t, 1>::from_fn(32, 32, pattern).unwrap();
+                let shifted =
+                    Mat::<$t, 1>::from_fn(32, 32, |r, c| pattern((r + 30) % 32, (c + 29) % 32))
+                        .unwrap();
+                let (shift, response) = src.phase_correlate(&shifted, None).unwrap();
+                assert!((shift.x - 3.).abs() < 0.1, "{:?}", shift);
+                assert!((shift.y - 2.).abs() < 0.1, "{:?}", shift);
+                assert!(response > 0.5);
+
+                let size = Size {
+                    width: 32,
+                    height: 32,
+                };
+                let window = Mat::<$t, 1>::create_hanning_window(size).unwrap();
+                assert!(src.phase_correlate(&shifted, Some(&window)).is_ok());
+            }
+        };
+    }
+    phase_correlate_test!(phase_correlate_32f_test, f32);
+    phase_correlate_test!(phase_correlate_64f_test, f64);
+}
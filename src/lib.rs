@@ -6,6 +6,7 @@ pub mod imgproc;
 pub mod result;
 
 pub use crate::core::*;
+pub use crate::result::{Error, Result};
 
 #[cfg(test)]
 impl Mat<u8, 3> {
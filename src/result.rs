@@ -1,14 +1,130 @@
-use crate::ffi::FFIResult;
+use crate::{
+    core::{type_name, FileNodeType},
+    ffi::{self, FFIResult},
+};
+use std::fmt;
 
-pub(crate) type Result<T> = std::result::Result<T, &'static str>;
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Error returned by every fallible rxcv call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// `cv::Exception` thrown by OpenCV.
+    OpenCV {
+        code: i32,
+        message: String,
+        function: String,
+        file: String,
+        line: i32,
+    },
+    /// Any other `std::exception` thrown on the C++ side.
+    Exception(String),
+    /// A C++ throw that is not derived from `std::exception`.
+    Unknown,
+    /// Arguments rejected on the Rust side before reaching OpenCV.
+    InvalidArgument(String),
+    /// A square matrix was required.
+    NotSquare { rows: i32, cols: i32 },
+    /// A Mat had a different type than requested, as `CV_MAKETYPE` values.
+    /// Displayed by name, e.g. `CV_32FC1`.
+    TypeMismatch { expected: i32, actual: i32 },
+    /// A [`FileNode`](crate::core::FileNode) held a different kind of value than requested.
+    NodeTypeMismatch {
+        expected: FileNodeType,
+        actual: FileNodeType,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::OpenCV {
+                code,
+                message,
+                function,
+                file,
+                line,
+            } => write!(
+                f,
+                "OpenCV error {} in {} ({}:{}): {}",
+                code, function, file, line, message
+            ),
+            Error::Exception(message) => write!(f, "C++ exception: {}", message),
+            Error::Unknown => write!(f, "unknown C++ exception"),
+            Error::InvalidArgument(message) => write!(f, "invalid argument: {}", message),
+            Error::NotSquare { rows, cols } => {
+                write!(f, "expected a square matrix, got {}x{}", rows, cols)
+            }
+            Error::TypeMismatch { expected, actual } => write!(
+                f,
+                "expected Mat type {}, got {}",
+                type_name(*expected),
+                type_name(*actual)
+            ),
+            Error::NodeTypeMismatch { expected, actual } => {
+                write!(f, "expected node type {}, got {}", expected, actual)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
 
 impl<T> From<FFIResult<T>> for Result<T> {
     fn from(value: FFIResult<T>) -> Self {
         if value.error.is_null() {
-            Ok(value.ok)
-        } else {
-            //TODO: Error message forwarding.
-            Err("Error")
+            return Ok(value.ok);
         }
+        let error = unsafe {
+            let e = &*value.error;
+            let error = match e.kind {
+                ffi::FFI_ERROR_OPENCV => Error::OpenCV {
+                    code: e.code,
+                    message: ffi::to_string(e.message),
+                    function: ffi::to_string(e.function),
+                    file: ffi::to_string(e.file),
+                    line: e.line,
+                },
+                ffi::FFI_ERROR_STD => Error::Exception(ffi::to_string(e.message)),
+                _ => Error::Unknown,
+            };
+            ffi::cv_release_error(value.error);
+            error
+        };
+        Err(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{core::Mat, imgproc::GaussianBlur, BorderTypes, Size};
+
+    #[test]
+    fn opencv_error_test() {
+        let src = Mat::<u8, 1>::from_shape(8, 8).unwrap();
+        let ksize = Size {
+            width: 2,
+            height: 2,
+        };
+        let err = src
+            .gaussian_blur(ksize, 0., 0., BorderTypes::BORDER_DEFAULT)
+            .err()
+            .unwrap();
+        match &err {
+            Error::OpenCV { code, message, .. } => {
+                assert_eq!(*code, -215);
+                assert!(!message.is_empty());
+            }
+            _ => panic!("unexpected error: {}", err),
+        }
+        assert!(err.to_string().starts_with("OpenCV error"));
+    }
+
+    #[test]
+    fn decode_error_test() {
+        let data: &[u8] = &[1, 2, 3, 4, 5, 6];
+        let err = Mat::<u8, 3>::decode(data).err().unwrap();
+        assert!(matches!(err, Error::OpenCV { .. }));
     }
 }